@@ -0,0 +1,184 @@
+use std::ops::RangeInclusive;
+
+use time::{Date, Month, error::ComponentRange};
+
+use crate::{
+    utils::try_from_ymd,
+    viewed_date::{YEARS_IN_YEAR_SELECTION, YearNumber, year_group_start},
+};
+
+/// Difference between a Buddhist Era (พ.ศ.) year and a Christian Era (ค.ศ.) year
+pub const BUDDHIST_ERA_OFFSET: YearNumber = 543;
+
+/// First Christian Era year in which the Thai new year started on 1 January
+pub const BUDDHIST_ERA_JANUARY_NEW_YEAR: YearNumber = 1941;
+
+/// Era used to label and group the years of the datepicker.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalendarEra {
+    /// Christian Era (ค.ศ.), years are the Gregorian years
+    #[default]
+    Christian,
+    /// Buddhist Era (พ.ศ.), years are the Gregorian years + 543
+    Buddhist {
+        /// If true, dates before 1941 follow the former Thai new year on 1 April,
+        /// so January to March belong to the previous Buddhist Era year
+        april_new_year: bool,
+    },
+}
+
+impl CalendarEra {
+    /// Returns the number of years to add to a Gregorian year to get the year of this era
+    pub const fn year_offset(&self) -> YearNumber {
+        match self {
+            CalendarEra::Christian => 0,
+            CalendarEra::Buddhist { .. } => BUDDHIST_ERA_OFFSET,
+        }
+    }
+
+    /// Returns the year of this era of the given Gregorian year
+    pub const fn from_gregorian_year(&self, year: YearNumber) -> YearNumber {
+        year + self.year_offset()
+    }
+
+    /// Returns the Gregorian year of the given year of this era
+    pub const fn to_gregorian_year(&self, era_year: YearNumber) -> YearNumber {
+        era_year - self.year_offset()
+    }
+
+    /// Returns the year of this era the given date belongs to
+    pub fn year(&self, date: &Date) -> YearNumber {
+        match self {
+            CalendarEra::Buddhist {
+                april_new_year: true,
+            } if date.year() < BUDDHIST_ERA_JANUARY_NEW_YEAR && date.month() < Month::April => {
+                self.from_gregorian_year(date.year()) - 1
+            }
+            _ => self.from_gregorian_year(date.year()),
+        }
+    }
+
+    /// Returns the first day of the given year of this era,
+    /// or an error if it is out of the range of `Date`
+    pub fn first_day_of_year(&self, era_year: YearNumber) -> Result<Date, ComponentRange> {
+        let year = self.to_gregorian_year(era_year);
+        match self {
+            CalendarEra::Buddhist {
+                april_new_year: true,
+            } if year < BUDDHIST_ERA_JANUARY_NEW_YEAR => try_from_ymd(year, 4, 1),
+            _ => try_from_ymd(year, 1, 1),
+        }
+    }
+
+    /// Returns the first year of this era of the year group including the given date
+    pub fn year_group_start_of(&self, date: &Date) -> YearNumber {
+        year_group_start(self.year(date))
+    }
+
+    /// Returns the first year of this era of the year group including the given Gregorian year
+    pub fn year_group_start(&self, year: YearNumber) -> YearNumber {
+        year_group_start(self.from_gregorian_year(year))
+    }
+
+    /// Returns the last year of this era of the year group including the given Gregorian year
+    pub fn year_group_end(&self, year: YearNumber) -> YearNumber {
+        self.year_group_start(year) + (YEARS_IN_YEAR_SELECTION - 1)
+    }
+
+    /// Returns the years of this era of the year group including the given Gregorian year
+    pub fn year_group_range(&self, year: YearNumber) -> RangeInclusive<YearNumber> {
+        self.year_group_start(year)..=self.year_group_end(year)
+    }
+
+    /// Returns the Gregorian years of the year group of this era including the given Gregorian year
+    pub fn gregorian_year_group_range(&self, year: YearNumber) -> RangeInclusive<YearNumber> {
+        self.to_gregorian_year(self.year_group_start(year))
+            ..=self.to_gregorian_year(self.year_group_end(year))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rstest_utils::create_date;
+
+    use rstest::*;
+
+    const BUDDHIST: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: false,
+    };
+    const BUDDHIST_APRIL: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: true,
+    };
+
+    #[rstest(
+        expected, era, date, //
+        case::christian(2024, CalendarEra::Christian, create_date(2024, 1, 1)),
+        case::buddhist(2567, BUDDHIST, create_date(2024, 1, 1)),
+        case::buddhist_before_1941(2473, BUDDHIST, create_date(1930, 2, 1)),
+        case::april_new_year_before_april(2472, BUDDHIST_APRIL, create_date(1930, 3, 31)),
+        case::april_new_year_from_april(2473, BUDDHIST_APRIL, create_date(1930, 4, 1)),
+        case::april_new_year_from_1941(2484, BUDDHIST_APRIL, create_date(1941, 1, 1)),
+        case::april_new_year_christian(1930, CalendarEra::Christian, create_date(1930, 3, 31)),
+    )]
+    fn year(expected: YearNumber, era: CalendarEra, date: Date) {
+        assert_eq!(expected, era.year(&date));
+    }
+
+    #[rstest(
+        era => [CalendarEra::Christian, BUDDHIST, BUDDHIST_APRIL],
+        year => [-100, 0, 1990, 2024],
+    )]
+    fn gregorian_year_round_trip(era: CalendarEra, year: YearNumber) {
+        assert_eq!(year, era.to_gregorian_year(era.from_gregorian_year(year)));
+    }
+
+    #[rstest(
+        expected, era, year, //
+        case::christian(1980..=1999, CalendarEra::Christian, 1990),
+        case::buddhist_in_middle(2560..=2579, BUDDHIST, 2024),
+        case::buddhist_at_start(2560..=2579, BUDDHIST, 2017),
+        case::buddhist_at_end(2560..=2579, BUDDHIST, 2036),
+        case::buddhist_next_group(2580..=2599, BUDDHIST, 2037),
+    )]
+    fn year_group_range(expected: RangeInclusive<YearNumber>, era: CalendarEra, year: YearNumber) {
+        assert_eq!(expected, era.year_group_range(year));
+    }
+
+    #[rstest(
+        expected, era, date, //
+        case::christian(1920, CalendarEra::Christian, create_date(1937, 3, 15)),
+        case::buddhist(2480, BUDDHIST, create_date(1937, 3, 15)),
+        case::april_new_year_before_april(2460, BUDDHIST_APRIL, create_date(1937, 3, 15)),
+        case::april_new_year_from_april(2480, BUDDHIST_APRIL, create_date(1937, 4, 1)),
+    )]
+    fn year_group_start_of(expected: YearNumber, era: CalendarEra, date: Date) {
+        assert_eq!(expected, era.year_group_start_of(&date));
+    }
+
+    #[rstest(
+        expected, era, era_year, //
+        case::christian(create_date(1937, 1, 1), CalendarEra::Christian, 1937),
+        case::buddhist(create_date(1937, 1, 1), BUDDHIST, 2480),
+        case::april_new_year(create_date(1937, 4, 1), BUDDHIST_APRIL, 2480),
+        case::april_new_year_from_1941(create_date(1941, 1, 1), BUDDHIST_APRIL, 2484),
+    )]
+    fn first_day_of_year(expected: Date, era: CalendarEra, era_year: YearNumber) {
+        assert_eq!(Ok(expected), era.first_day_of_year(era_year));
+    }
+
+    #[rstest(
+        expected, era, year, //
+        case::christian(1980..=1999, CalendarEra::Christian, 1990),
+        case::buddhist(2017..=2036, BUDDHIST, 2024),
+        case::buddhist_previous_group(1997..=2016, BUDDHIST, 2016),
+    )]
+    fn gregorian_year_group_range(
+        expected: RangeInclusive<YearNumber>,
+        era: CalendarEra,
+        year: YearNumber,
+    ) {
+        assert_eq!(expected, era.gregorian_year_group_range(year));
+    }
+}
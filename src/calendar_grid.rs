@@ -0,0 +1,404 @@
+use std::collections::{HashMap, HashSet};
+
+use derive_builder::Builder;
+use derive_getters::Getters;
+use time::{Date, PrimitiveDateTime, Weekday};
+
+use crate::{
+    config::date_constraints::HasDateConstraints,
+    viewed_date::{ViewedDate, days_since},
+    week_numbering::WeekNumbering,
+};
+
+/// Options of a `CalendarGrid`.
+#[derive(Clone, Debug, Builder, Getters)]
+#[builder(default)]
+pub struct GridOptions {
+    /// First day of the week, the weekday of the first column
+    first_weekday: Weekday,
+
+    /// If true, the grid has always 6 rows, otherwise only the rows including a day of the month
+    six_weeks: bool,
+
+    /// Numbering of the weeks of the rows
+    week_numbering: WeekNumbering,
+
+    /// Current date, to flag its cell
+    #[builder(setter(strip_option))]
+    today: Option<Date>,
+
+    /// Selected dates, to flag their cells
+    selected: HashSet<Date>,
+
+    /// Weekdays of the weekend, to flag their cells
+    weekend: HashSet<Weekday>,
+
+    /// Holidays with their names, to flag their cells
+    holidays: HashMap<Date, String>,
+}
+
+impl Default for GridOptions {
+    fn default() -> Self {
+        Self {
+            first_weekday: Weekday::Sunday,
+            six_weeks: false,
+            week_numbering: WeekNumbering::Iso,
+            today: None,
+            selected: HashSet::new(),
+            weekend: [Weekday::Saturday, Weekday::Sunday].into_iter().collect(),
+            holidays: HashMap::new(),
+        }
+    }
+}
+
+/// Day cell of a `CalendarGrid`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarCell {
+    /// Datetime of the cell, the nearest day in the range of `Date` for a cell out of its range
+    pub datetime: PrimitiveDateTime,
+    /// The day is out of the range of `Date`, the cell only pads the row
+    /// at the edges of the range and is also outside the month and forbidden
+    pub is_out_of_range: bool,
+    /// The day is in the previous or the next month of the viewed month
+    pub is_outside_month: bool,
+    /// The day is `GridOptions::today`
+    pub is_today: bool,
+    /// The day is in `GridOptions::selected`
+    pub is_selected: bool,
+    /// The day is forbidden by the date constraints
+    pub is_forbidden: bool,
+    /// The weekday is in `GridOptions::weekend`
+    pub is_weekend: bool,
+    /// Name of the holiday if the day is in `GridOptions::holidays`
+    pub holiday: Option<String>,
+}
+
+impl CalendarCell {
+    /// Returns true if the day is a holiday
+    pub fn is_holiday(&self) -> bool {
+        self.holiday.is_some()
+    }
+}
+
+/// Row of 7 day cells of a `CalendarGrid`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarRow {
+    /// Number of the week of the row, the week including the middle day of the row,
+    /// or the day nearest to it in the range of `Date`
+    pub week_number: u8,
+    /// Day cells of the row, always 7
+    pub cells: Vec<CalendarCell>,
+}
+
+impl CalendarRow {
+    /// Returns the datetime of the first cell of the row in the range of `Date`
+    pub fn first_datetime(&self) -> PrimitiveDateTime {
+        self.cells()
+            .next()
+            .expect("a row has a day in the range of `Date`")
+            .datetime
+    }
+
+    /// Returns the cells of the row in the range of `Date`
+    pub fn cells(&self) -> impl Iterator<Item = &CalendarCell> {
+        self.cells.iter().filter(|cell| !cell.is_out_of_range)
+    }
+
+    /// Returns true if every day of the row is forbidden, so the week can not be selected
+    pub fn is_forbidden(&self) -> bool {
+        self.cells().all(|cell| cell.is_forbidden)
+    }
+}
+
+/// Days view of a month, as rows of 7 day cells starting on `GridOptions::first_weekday`.
+#[derive(Debug, Clone, Getters)]
+pub struct CalendarGrid {
+    /// Weekdays of the columns, to display as headers
+    weekdays: [Weekday; 7],
+
+    /// Rows of 7 day cells, covering the whole viewed month,
+    /// without the rows entirely out of the range of `Date`,
+    /// the days out of the range of the other rows are flagged `is_out_of_range`
+    rows: Vec<CalendarRow>,
+}
+
+impl CalendarGrid {
+    /// Creates the grid of the month of the given viewed date
+    pub fn new<T: HasDateConstraints>(
+        viewed_date: &PrimitiveDateTime,
+        options: &GridOptions,
+        config: &T,
+    ) -> Self {
+        let month = viewed_date.month();
+        let dates = viewed_date.calendar_dates(options.first_weekday, options.six_weeks);
+        // the days out of the range of `Date` are left out by `calendar_dates`,
+        // the days before `Date::MIN` are padded to keep the columns of the weekdays
+        let leading = dates
+            .first()
+            .filter(|first| first.day() == 1)
+            .map_or(0, |first| {
+                usize::from(days_since(first.weekday(), options.first_weekday))
+            });
+        let mut cells: Vec<Option<PrimitiveDateTime>> = std::iter::repeat_n(None, leading)
+            .chain(dates.into_iter().map(Some))
+            .collect();
+        cells.resize(cells.len().div_ceil(7) * 7, None);
+        let rows = cells
+            .chunks(7)
+            .map(|week| {
+                // the middle day, or the nearest day to it at the edges of the range of `Date`
+                let (_, middle) = week
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(n, datetime)| datetime.map(|datetime| (n.abs_diff(3), datetime)))
+                    .min()
+                    .expect("a row has a day in the range of `Date`");
+                CalendarRow {
+                    week_number: options.week_numbering.week_number(&middle.date()),
+                    cells: week
+                        .iter()
+                        .enumerate()
+                        .map(|(n, datetime)| match datetime {
+                            Some(datetime) => {
+                                let date = datetime.date();
+                                CalendarCell {
+                                    datetime: *datetime,
+                                    is_out_of_range: false,
+                                    is_outside_month: date.month() != month,
+                                    is_today: options.today == Some(date),
+                                    is_selected: options.selected.contains(&date),
+                                    is_forbidden: config.is_day_forbidden(datetime),
+                                    is_weekend: options.weekend.contains(&date.weekday()),
+                                    holiday: options.holidays.get(&date).cloned(),
+                                }
+                            }
+                            None => CalendarCell {
+                                datetime: nearest(week, n),
+                                is_out_of_range: true,
+                                is_outside_month: true,
+                                is_today: false,
+                                is_selected: false,
+                                is_forbidden: true,
+                                is_weekend: false,
+                                holiday: None,
+                            },
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        Self {
+            weekdays: std::array::from_fn(|n| options.first_weekday.nth_next(n as u8)),
+            rows,
+        }
+    }
+
+    /// Returns all the cells of the grid in the range of `Date`, row after row
+    pub fn cells(&self) -> impl Iterator<Item = &CalendarCell> {
+        self.rows.iter().flat_map(CalendarRow::cells)
+    }
+}
+
+/// Returns the day of the row nearest to the given column in the range of `Date`,
+/// the first day of the range for the leading columns and the last day for the trailing ones
+fn nearest(week: &[Option<PrimitiveDateTime>], column: usize) -> PrimitiveDateTime {
+    let mut datetimes = week.iter().flatten();
+    let datetime = if week[..column].iter().any(Option::is_some) {
+        datetimes.next_back()
+    } else {
+        datetimes.next()
+    };
+    *datetime.expect("a row has a day in the range of `Date`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::date_constraints::{DateConstraints, DateConstraintsBuilder};
+
+    use rstest::*;
+    use time::macros::{date, datetime};
+
+    #[rstest(
+        expected_rows, expected_first, expected_last, viewed_date, first_weekday, //
+        case::sunday_start(5, datetime!(2024-04-28 00:00), datetime!(2024-06-01 00:00), datetime!(2024-05-15 00:00), Weekday::Sunday),
+        case::monday_start(5, datetime!(2024-04-29 00:00), datetime!(2024-06-02 00:00), datetime!(2024-05-15 00:00), Weekday::Monday),
+        case::starts_on_first_weekday(4, datetime!(2015-02-01 00:00), datetime!(2015-02-28 00:00), datetime!(2015-02-10 00:00), Weekday::Sunday),
+        case::six_rows(6, datetime!(2024-08-26 00:00), datetime!(2024-10-06 00:00), datetime!(2024-09-01 00:00), Weekday::Monday),
+    )]
+    fn grid_dates(
+        expected_rows: usize,
+        expected_first: PrimitiveDateTime,
+        expected_last: PrimitiveDateTime,
+        viewed_date: PrimitiveDateTime,
+        first_weekday: Weekday,
+    ) {
+        let options = GridOptionsBuilder::default()
+            .first_weekday(first_weekday)
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(&viewed_date, &options, &DateConstraints::default());
+        assert_eq!(expected_rows, grid.rows().len());
+        assert!(grid.rows().iter().all(|row| row.cells.len() == 7));
+        assert_eq!(expected_first, grid.cells().next().unwrap().datetime);
+        assert_eq!(expected_last, grid.cells().last().unwrap().datetime);
+        assert_eq!(first_weekday, grid.weekdays()[0]);
+        assert!(
+            grid.cells()
+                .enumerate()
+                .all(|(n, cell)| cell.datetime.weekday() == grid.weekdays()[n % 7])
+        );
+    }
+
+    #[test]
+    fn grid_cell_flags() {
+        let options = GridOptionsBuilder::default()
+            .today(date!(2024 - 05 - 15))
+            .selected([date!(2024 - 05 - 20)].into_iter().collect())
+            .holidays(
+                [(date!(2024 - 05 - 22), "วันวิสาขบูชา".to_string())]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        let config = DateConstraintsBuilder::default()
+            .max_datetime(datetime!(2024-05-25 00:00))
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(&datetime!(2024-05-01 00:00), &options, &config);
+        let cell = |date: Date| {
+            grid.cells()
+                .find(|cell| cell.datetime.date() == date)
+                .unwrap()
+        };
+
+        assert!(cell(date!(2024 - 04 - 30)).is_outside_month);
+        assert!(!cell(date!(2024 - 05 - 01)).is_outside_month);
+        assert!(cell(date!(2024 - 05 - 15)).is_today);
+        assert!(!cell(date!(2024 - 05 - 16)).is_today);
+        assert!(cell(date!(2024 - 05 - 20)).is_selected);
+        assert!(cell(date!(2024 - 05 - 26)).is_forbidden);
+        assert!(!cell(date!(2024 - 05 - 25)).is_forbidden);
+        assert!(cell(date!(2024 - 05 - 18)).is_weekend);
+        assert!(cell(date!(2024 - 05 - 19)).is_weekend);
+        assert!(!cell(date!(2024 - 05 - 17)).is_weekend);
+        assert!(cell(date!(2024 - 05 - 22)).is_holiday());
+        assert_eq!(
+            Some("วันวิสาขบูชา".to_string()),
+            cell(date!(2024 - 05 - 22)).holiday
+        );
+    }
+
+    #[test]
+    fn grid_six_weeks() {
+        let options = GridOptionsBuilder::default()
+            .six_weeks(true)
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(
+            &datetime!(2015-02-10 00:00),
+            &options,
+            &DateConstraints::default(),
+        );
+        assert_eq!(6, grid.rows().len());
+        assert_eq!(
+            datetime!(2015-03-14 00:00),
+            grid.cells().last().unwrap().datetime
+        );
+    }
+
+    #[rstest(
+        expected, week_numbering, first_weekday, //
+        case::iso(vec![1, 2, 3, 4, 5], WeekNumbering::Iso, Weekday::Monday),
+        case::us(vec![1, 2, 3, 4, 5], WeekNumbering::Us, Weekday::Sunday),
+        case::iso_sunday_start(vec![1, 2, 3, 4, 5], WeekNumbering::Iso, Weekday::Sunday),
+    )]
+    fn grid_week_numbers(expected: Vec<u8>, week_numbering: WeekNumbering, first_weekday: Weekday) {
+        let options = GridOptionsBuilder::default()
+            .week_numbering(week_numbering)
+            .first_weekday(first_weekday)
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(
+            &datetime!(2024-01-10 00:00),
+            &options,
+            &DateConstraints::default(),
+        );
+        assert_eq!(
+            expected,
+            grid.rows()
+                .iter()
+                .map(|row| row.week_number)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest(
+        expected_leading, expected_trailing, viewed_date, first_weekday, six_weeks, //
+        case::min_monday_start(0, 0, datetime!(-9999-01-15 00:00), Weekday::Monday, false),
+        case::min_sunday_start(1, 0, datetime!(-9999-01-15 00:00), Weekday::Sunday, false),
+        case::min_saturday_start(2, 0, datetime!(-9999-01-15 00:00), Weekday::Saturday, true),
+        case::max_sunday_start(0, 1, datetime!(9999-12-15 00:00), Weekday::Sunday, false),
+        case::max_monday_start(0, 2, datetime!(9999-12-15 00:00), Weekday::Monday, true),
+    )]
+    fn grid_at_date_edges(
+        expected_leading: usize,
+        expected_trailing: usize,
+        viewed_date: PrimitiveDateTime,
+        first_weekday: Weekday,
+        six_weeks: bool,
+    ) {
+        let options = GridOptionsBuilder::default()
+            .first_weekday(first_weekday)
+            .six_weeks(six_weeks)
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(&viewed_date, &options, &DateConstraints::default());
+        assert!(grid.rows().iter().all(|row| row.cells.len() == 7));
+        let cells: Vec<_> = grid.rows().iter().flat_map(|row| &row.cells).collect();
+        assert_eq!(
+            expected_leading,
+            cells.iter().take_while(|cell| cell.is_out_of_range).count()
+        );
+        assert_eq!(
+            expected_trailing,
+            cells
+                .iter()
+                .rev()
+                .take_while(|cell| cell.is_out_of_range)
+                .count()
+        );
+        assert!(cells.iter().enumerate().all(|(n, cell)| {
+            cell.is_out_of_range || cell.datetime.weekday() == grid.weekdays()[n % 7]
+        }));
+        assert!(
+            cells
+                .iter()
+                .filter(|cell| cell.is_out_of_range)
+                .all(|cell| cell.is_forbidden
+                    && cell.is_outside_month
+                    && (cell.datetime.date() == Date::MIN || cell.datetime.date() == Date::MAX))
+        );
+        assert_eq!(
+            cells.len() - expected_leading - expected_trailing,
+            grid.cells().count()
+        );
+    }
+
+    #[test]
+    fn grid_forbidden_row() {
+        let config = DateConstraintsBuilder::default()
+            .max_datetime(datetime!(2024-05-25 00:00))
+            .build()
+            .unwrap();
+        let grid = CalendarGrid::new(
+            &datetime!(2024-05-01 00:00),
+            &GridOptions::default(),
+            &config,
+        );
+        assert_eq!(datetime!(2024-05-19 00:00), grid.rows()[3].first_datetime());
+        assert!(!grid.rows()[3].is_forbidden());
+        assert!(grid.rows()[4].is_forbidden());
+    }
+}
@@ -0,0 +1,69 @@
+use time::OffsetDateTime;
+
+/// Trait that can be implemented to provide the current datetime to the datepicker.
+pub trait Clock {
+    /// Returns the current datetime in UTC.
+    fn now_utc(&self) -> OffsetDateTime;
+}
+
+/// Clock of the operating system, using `OffsetDateTime::now_utc`
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// Clock of the browser, using `js_sys::Date::now`
+#[cfg(feature = "wasm")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WasmClock;
+
+#[cfg(feature = "wasm")]
+impl Clock for WasmClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        let ts_milli = js_sys::Date::now() as i128;
+        OffsetDateTime::from_unix_timestamp_nanos(ts_milli * 1_000_000)
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+/// Clock always returning the same datetime, useful for tests
+#[derive(Debug, Copy, Clone)]
+pub struct FixedClock(pub OffsetDateTime);
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "wasm", target_arch = "wasm32"))] {
+        /// Clock used when no clock is given, `WasmClock` in the browser
+        pub type DefaultClock = WasmClock;
+    } else {
+        /// Clock used when no clock is given, `SystemClock` on native targets
+        pub type DefaultClock = SystemClock;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::macros::datetime;
+
+    #[test]
+    fn fixed_clock_returns_given_datetime() {
+        let now = datetime!(2024-01-01 12:30 UTC);
+        assert_eq!(now, FixedClock(now).now_utc());
+    }
+
+    #[test]
+    fn system_clock_is_after_epoch() {
+        assert!(SystemClock.now_utc() > OffsetDateTime::UNIX_EPOCH);
+    }
+}
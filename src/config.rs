@@ -1,24 +1,57 @@
+pub mod combinators;
 pub mod date_constraints;
+pub mod date_ranges;
+pub mod error;
+pub mod recurrence;
+#[cfg(feature = "serde")]
+pub mod serde_formats;
 
 use derive_builder::Builder;
 use derive_getters::Getters;
-use time::{OffsetDateTime, PrimitiveDateTime, macros::offset};
+use time::{PrimitiveDateTime, Time, UtcOffset, Weekday, macros::offset};
 
-use self::date_constraints::HasDateConstraints;
+use self::{date_constraints::HasDateConstraints, error::ConfigError};
 
-use crate::dialog_view_type::DialogViewType;
+use crate::{
+    calendar_era::CalendarEra,
+    calendar_grid::GridOptionsBuilder,
+    clock::{Clock, DefaultClock},
+    dialog_view_type::DialogViewType,
+    fiscal_calendar::FiscalCalendar,
+    time_slots::{DEFAULT_MINUTE_STEP, allowed_time_slots},
+    utils::{is_year_group_forbidden_in, nearest_allowed_day},
+    viewed_date::ViewedDate,
+    week_numbering::WeekNumbering,
+};
+
+/// UTC offset of Thailand, used when `PickerConfig::utc_offset` is not set
+pub const DEFAULT_UTC_OFFSET: UtcOffset = offset!(+7);
 
 /// Configuration for the datepicker.
-#[derive(Clone, Default, Debug, Builder, Getters)]
+#[derive(Clone, Debug, Builder, Getters)]
 #[builder(setter(strip_option))]
 #[builder(default)]
-#[builder(build_fn(validate = "Self::validate"))]
+#[builder(build_fn(validate = "Self::validate", error = "ConfigError"))]
+#[cfg_attr(feature = "serde", builder(derive(serde::Deserialize)))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "PickerConfigBuilder<T>")
+)]
 pub struct PickerConfig<T: HasDateConstraints + Default + Clone> {
     /// Possible constraints to prevent the user from selecting some dates
     #[getter(skip)]
     date_constraints: T,
 
     /// Initializes the datepicker to this value
+    #[cfg_attr(feature = "serde", serde(with = "serde_formats::option_iso_datetime"))]
+    #[cfg_attr(
+        feature = "serde",
+        builder_field_attr(serde(
+            default,
+            deserialize_with = "serde_formats::builder_iso_datetime"
+        ))
+    )]
     initial_date: Option<PrimitiveDateTime>,
 
     /// Initializes the view type to this value
@@ -26,6 +59,49 @@ pub struct PickerConfig<T: HasDateConstraints + Default + Clone> {
 
     /// Selection type, to make it possible to select for example only a year, or only a month.
     selection_type: DialogViewType,
+
+    /// Era used to label the years and to align the year groups of the years view
+    calendar_era: CalendarEra,
+
+    /// Fiscal years of the years and months views, the calendar years by default
+    fiscal_calendar: FiscalCalendar,
+
+    /// UTC offset of the user, to get the current local datetime,
+    /// `DEFAULT_UTC_OFFSET` if not set
+    utc_offset: Option<UtcOffset>,
+
+    /// First day of the week of the days view
+    first_weekday: Weekday,
+
+    /// If true, the days view has always 6 rows of days
+    six_weeks: bool,
+
+    /// Numbering of the weeks displayed in the days view
+    week_numbering: WeekNumbering,
+
+    /// Step in minutes between two selectable times of the minutes view, must divide 60
+    minute_step: u8,
+}
+
+impl<T> Default for PickerConfig<T>
+where
+    T: HasDateConstraints + std::default::Default + Clone,
+{
+    fn default() -> Self {
+        Self {
+            date_constraints: T::default(),
+            initial_date: None,
+            initial_view_type: DialogViewType::default(),
+            selection_type: DialogViewType::default(),
+            calendar_era: CalendarEra::default(),
+            fiscal_calendar: FiscalCalendar::default(),
+            utc_offset: None,
+            first_weekday: Weekday::Sunday,
+            six_weeks: false,
+            week_numbering: WeekNumbering::default(),
+            minute_step: DEFAULT_MINUTE_STEP,
+        }
+    }
 }
 
 impl<T> HasDateConstraints for PickerConfig<T>
@@ -44,12 +120,70 @@ where
         self.date_constraints.is_month_forbidden(year_month_info)
     }
 
+    fn is_hour_forbidden(&self, hour: &PrimitiveDateTime) -> bool {
+        self.date_constraints.is_hour_forbidden(hour)
+    }
+
+    fn is_week_forbidden(&self, week_start: &PrimitiveDateTime) -> bool {
+        self.date_constraints.is_week_forbidden(week_start)
+    }
+
+    /// Returns true if the entire fiscal quarter including the given datetime is forbidden.
+    fn is_quarter_forbidden(&self, quarter: &PrimitiveDateTime) -> bool {
+        if self.fiscal_calendar.is_calendar_year() {
+            return self.date_constraints.is_quarter_forbidden(quarter);
+        }
+        self.are_months_forbidden(&self.fiscal_calendar.first_day_of_quarter(quarter), 3)
+    }
+
+    /// Returns true if the entire fiscal half year including the given datetime is forbidden.
+    fn is_half_year_forbidden(&self, half_year: &PrimitiveDateTime) -> bool {
+        if self.fiscal_calendar.is_calendar_year() {
+            return self.date_constraints.is_half_year_forbidden(half_year);
+        }
+        self.are_months_forbidden(&self.fiscal_calendar.first_day_of_half_year(half_year), 6)
+    }
+
+    /// Returns true if the entire fiscal year including the given datetime is forbidden.
     fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
-        self.date_constraints.is_year_forbidden(year)
+        if self.fiscal_calendar.is_calendar_year() {
+            return self.date_constraints.is_year_forbidden(year);
+        }
+        self.fiscal_calendar
+            .months(year)
+            .iter()
+            .all(|month| self.date_constraints.is_month_forbidden(month))
     }
 
+    /// Returns true if the entire group of years of the `calendar_era` including the given year is forbidden,
+    /// the group of fiscal years if the `fiscal_calendar` does not start in January.
     fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
-        self.date_constraints.is_year_group_forbidden(year)
+        if self.fiscal_calendar.is_calendar_year() {
+            return is_year_group_forbidden_in(&self.date_constraints, &self.calendar_era, year);
+        }
+        // the fiscal years out of the range of `Date` can not be selected
+        self.fiscal_calendar
+            .year_group_range(&self.calendar_era, &year.date())
+            .all(|fiscal_year| {
+                self.fiscal_calendar
+                    .try_first_day(fiscal_year)
+                    .ok()
+                    .is_none_or(|first_day| {
+                        self.is_year_forbidden(&PrimitiveDateTime::new(first_day, year.time()))
+                    })
+            })
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        self.date_constraints.time_boundaries(day)
+    }
+
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        self.date_constraints.min_bound()
+    }
+
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        self.date_constraints.max_bound()
     }
 }
 
@@ -57,47 +191,113 @@ impl<T> PickerConfigBuilder<T>
 where
     T: HasDateConstraints + std::default::Default + Clone,
 {
-    fn validate(&self) -> Result<(), String> {
-        if self.initial_view_type > self.selection_type {
-            return Err("initial_view_type can have at most selection_type scale".into());
+    fn validate(&self) -> Result<(), ConfigError> {
+        let initial_view_type = self.initial_view_type.unwrap_or_default();
+        let selection_type = self.selection_type.unwrap_or_default();
+        if initial_view_type > selection_type {
+            return Err(ConfigError::InitialViewBeyondSelection {
+                initial_view_type,
+                selection_type,
+            });
+        }
+        if !initial_view_type.reaches(&selection_type) {
+            return Err(ConfigError::UnreachableSelection {
+                initial_view_type,
+                selection_type,
+            });
+        }
+        if let Some(minute_step) = self.minute_step
+            && (minute_step == 0 || 60 % minute_step != 0)
+        {
+            return Err(ConfigError::InvalidMinuteStep { minute_step });
         }
         if let (Some(Some(initial_date)), Some(date_constraints)) =
             (self.initial_date, &self.date_constraints)
+            && date_constraints.is_day_forbidden(&initial_date)
         {
-            if date_constraints.is_day_forbidden(&initial_date) {
-                return Err(format!(
-                    "The initial_date {initial_date} is forbidden by the date_constraints."
-                ));
-            }
+            return Err(ConfigError::InitialDateForbidden { date: initial_date });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T> TryFrom<PickerConfigBuilder<T>> for PickerConfig<T>
+where
+    T: HasDateConstraints + std::default::Default + Clone,
+{
+    type Error = ConfigError;
+
+    /// Builds the deserialized builder, to validate the deserialized config
+    fn try_from(builder: PickerConfigBuilder<T>) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
 impl<T> PickerConfig<T>
 where
     T: HasDateConstraints + std::default::Default + Clone,
 {
-    pub fn guess_allowed_year_month(&self) -> PrimitiveDateTime {
-        if let Some(init_date) = self.initial_date {
-            return init_date;
-        }
-        // if none of the above constraints matched use the current datetime
-        let ts_milli = js_sys::Date::now() as i64;
-        let local = OffsetDateTime::from_unix_timestamp(ts_milli.saturating_div(1_000))
-            .unwrap()
-            .to_offset(offset!(+7));
+    /// Returns true if the given number of months from the given month are all forbidden
+    fn are_months_forbidden(&self, first_month: &PrimitiveDateTime, months: usize) -> bool {
+        std::iter::successors(Some(*first_month), |month| Some(month.next_month()))
+            .take(months)
+            .all(|month| self.date_constraints.is_month_forbidden(&month))
+    }
+
+    /// Returns the current local datetime of the given clock in the configured `utc_offset`
+    pub fn now<C: Clock>(&self, clock: &C) -> PrimitiveDateTime {
+        let local = clock
+            .now_utc()
+            .to_offset(self.utc_offset.unwrap_or(DEFAULT_UTC_OFFSET));
         PrimitiveDateTime::new(local.date(), local.time())
     }
+
+    /// Returns the datetime to open the datepicker on, the allowed day and time nearest to
+    /// the `initial_date` or to the current datetime if there is no `initial_date`,
+    /// None if no allowed day can be found by the date_constraints
+    pub fn guess_allowed_year_month(&self) -> Option<PrimitiveDateTime> {
+        self.guess_allowed_year_month_with(&DefaultClock::default())
+    }
+
+    /// Same as `guess_allowed_year_month`, with the current datetime given by the clock
+    pub fn guess_allowed_year_month_with<C: Clock>(&self, clock: &C) -> Option<PrimitiveDateTime> {
+        let start = self.initial_date.unwrap_or_else(|| self.now(clock));
+        nearest_allowed_day(&self.date_constraints, &start)
+    }
+
+    /// Returns the time slots every `minute_step` minutes of the day including the given datetime
+    /// that are not forbidden by the date_constraints
+    pub fn allowed_time_slots(&self, day: &PrimitiveDateTime) -> Vec<PrimitiveDateTime> {
+        allowed_time_slots(&self.date_constraints, day, self.minute_step)
+    }
+
+    /// Returns a `GridOptionsBuilder` with the `first_weekday`, `six_weeks` and `week_numbering`
+    /// of this config
+    pub fn grid_options(&self) -> GridOptionsBuilder {
+        let mut builder = GridOptionsBuilder::default();
+        builder
+            .first_weekday(self.first_weekday)
+            .six_weeks(self.six_weeks)
+            .week_numbering(self.week_numbering);
+        builder
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::date_constraints::MockHasDateConstraints;
+    use crate::config::date_constraints::{
+        DateConstraints, DateConstraintsBuilder, MockHasDateConstraints, TimeWindow,
+    };
+
+    use crate::clock::FixedClock;
 
     use mockall::predicate;
-    use time::{Date, Month, Time};
+    use time::{
+        Date, Month, Time, Weekday,
+        macros::{datetime, time},
+    };
 
     #[test]
     fn picker_config_initial_view_type_greater_than_selection_type() {
@@ -105,10 +305,12 @@ mod tests {
             .initial_view_type(DialogViewType::Days)
             .selection_type(DialogViewType::Months)
             .build();
-        assert!(config.is_err());
         assert_eq!(
-            config.unwrap_err().to_string(),
-            "initial_view_type can have at most selection_type scale"
+            config.unwrap_err(),
+            ConfigError::InitialViewBeyondSelection {
+                initial_view_type: DialogViewType::Days,
+                selection_type: DialogViewType::Months,
+            }
         );
     }
 
@@ -130,6 +332,50 @@ mod tests {
         assert!(config.is_ok());
     }
 
+    #[test]
+    fn picker_config_selection_type_unreachable_from_weeks() {
+        let config = PickerConfigBuilder::<MockHasDateConstraints>::default()
+            .initial_view_type(DialogViewType::Weeks)
+            .selection_type(DialogViewType::Days)
+            .build();
+        assert_eq!(
+            config.unwrap_err(),
+            ConfigError::UnreachableSelection {
+                initial_view_type: DialogViewType::Weeks,
+                selection_type: DialogViewType::Days,
+            }
+        );
+    }
+
+    #[test]
+    fn picker_config_selection_type_unreachable_from_year_parts() {
+        for (initial_view_type, selection_type) in [
+            (DialogViewType::Quarters, DialogViewType::Months),
+            (DialogViewType::HalfYears, DialogViewType::Quarters),
+            (DialogViewType::HalfYears, DialogViewType::Days),
+        ] {
+            let config = PickerConfigBuilder::<MockHasDateConstraints>::default()
+                .initial_view_type(initial_view_type)
+                .selection_type(selection_type)
+                .build();
+            assert_eq!(
+                config.unwrap_err(),
+                ConfigError::UnreachableSelection {
+                    initial_view_type,
+                    selection_type,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn picker_config_initial_view_type_without_selection_type() {
+        let config = PickerConfigBuilder::<MockHasDateConstraints>::default()
+            .initial_view_type(DialogViewType::Years)
+            .build();
+        assert!(config.is_ok());
+    }
+
     #[test]
     fn picker_config_initial_date_forbidden() {
         let mut date_constraints_mock = MockHasDateConstraints::new();
@@ -143,10 +389,11 @@ mod tests {
             ))
             .date_constraints(date_constraints_mock)
             .build();
-        assert!(config.is_err());
         assert_eq!(
-            config.unwrap_err().to_string(),
-            "The initial_date 2020-01-01 0:00:00.0 is forbidden by the date_constraints."
+            config.unwrap_err(),
+            ConfigError::InitialDateForbidden {
+                date: datetime!(2020-01-01 00:00)
+            }
         );
     }
 
@@ -164,6 +411,13 @@ mod tests {
             initial_date: *config.initial_date(),
             initial_view_type: *config.initial_view_type(),
             selection_type: *config.selection_type(),
+            calendar_era: *config.calendar_era(),
+            fiscal_calendar: *config.fiscal_calendar(),
+            utc_offset: *config.utc_offset(),
+            first_weekday: *config.first_weekday(),
+            six_weeks: *config.six_weeks(),
+            week_numbering: *config.week_numbering(),
+            minute_step: *config.minute_step(),
         }
     }
 
@@ -194,7 +448,7 @@ mod tests {
         let mut date_constraints_mock = MockHasDateConstraints::new();
         date_constraints_mock
             .expect_is_month_forbidden()
-            .with(predicate::eq(year_month.clone()))
+            .with(predicate::eq(year_month))
             .times(1)
             .returning(|_| true);
         let builder = PickerConfigBuilder::default();
@@ -239,17 +493,378 @@ mod tests {
         assert!(config.is_year_group_forbidden(&year));
     }
 
+    #[test]
+    fn is_year_group_forbidden_in_buddhist_era() {
+        let create_config = |disabled_years: std::ops::RangeInclusive<i32>| {
+            PickerConfigBuilder::default()
+                .calendar_era(CalendarEra::Buddhist {
+                    april_new_year: false,
+                })
+                .date_constraints(
+                    DateConstraintsBuilder::default()
+                        .disabled_years(disabled_years.collect())
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+        };
+        // the Buddhist year group 2560-2579 is from 2017 to 2036
+        assert!(create_config(2017..=2036).is_year_group_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(!create_config(2020..=2039).is_year_group_forbidden(&datetime!(2024-05-15 00:00)));
+    }
+
     #[test]
     fn guess_allowed_year_month_with_initial_date() {
         let initial_date = PrimitiveDateTime::new(
             Date::from_calendar_date(2020, Month::March, 24).expect("invalid date"),
             Time::from_hms(0, 0, 0).expect("invalid time"),
         );
-        let config = PickerConfigBuilder::<MockHasDateConstraints>::default()
+        let mut date_constraints_mock = MockHasDateConstraints::new();
+        date_constraints_mock
+            .expect_is_day_forbidden()
+            .with(predicate::eq(initial_date))
+            .times(1)
+            .returning(|_| false);
+        date_constraints_mock
+            .expect_is_datetime_forbidden()
+            .with(predicate::eq(initial_date))
+            .times(1)
+            .returning(|_| false);
+        let builder = PickerConfigBuilder::default()
             .initial_date(initial_date)
+            .clone();
+        let config =
+            create_picker_config_with_mocked_date_constraints(builder, date_constraints_mock);
+        let expected = Some(initial_date);
+        assert_eq!(expected, config.guess_allowed_year_month());
+    }
+
+    #[test]
+    fn guess_allowed_year_month_without_initial_date_default_offset() {
+        let clock = FixedClock(datetime!(2020-12-31 20:00 UTC));
+        let config = PickerConfigBuilder::<DateConstraints>::default()
             .build()
             .unwrap();
-        let expected = initial_date;
-        assert_eq!(expected, config.guess_allowed_year_month());
+        assert_eq!(
+            Some(datetime!(2021-01-01 03:00)),
+            config.guess_allowed_year_month_with(&clock)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_without_initial_date_configured_offset() {
+        let clock = FixedClock(datetime!(2020-12-31 20:00 UTC));
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .utc_offset(offset!(-5))
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2020-12-31 15:00)),
+            config.guess_allowed_year_month_with(&clock)
+        );
+    }
+
+    fn guess_with_constraints(date_constraints: DateConstraints) -> Option<PrimitiveDateTime> {
+        // a Wednesday
+        let clock = FixedClock(datetime!(2024-05-15 03:00 UTC));
+        PickerConfigBuilder::default()
+            .date_constraints(date_constraints)
+            .build()
+            .unwrap()
+            .guess_allowed_year_month_with(&clock)
+    }
+
+    #[test]
+    fn guess_allowed_year_month_before_min_datetime() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-08-20 00:00))
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2024-08-20 10:00)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_after_max_datetime() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .max_datetime(datetime!(2021-02-03 12:00))
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2021-02-03 10:00)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_after_min_datetime_of_the_day() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-08-20 12:30))
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2024-08-20 12:30)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_nearest_allowed_time() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .disabled_time_windows(
+                [(
+                    Weekday::Wednesday,
+                    vec![TimeWindow::new(time!(08:00), time!(10:45))],
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2024-05-15 10:45)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_nearest_allowed_time_earlier() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .disabled_time_windows(
+                [(
+                    Weekday::Wednesday,
+                    vec![TimeWindow::new(time!(09:50), Time::MIDNIGHT)],
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2024-05-15 09:49)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_nearest_allowed_weekday() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .disabled_weekdays(
+                [Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        // Monday and Friday are equally near, the later one wins
+        assert_eq!(
+            Some(datetime!(2024-05-17 10:00)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_skips_forbidden_years() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2020-01-01 00:00))
+            .disabled_years([2024, 2025].into_iter().collect())
+            .disabled_months([Month::December].into_iter().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2023-11-30 10:00)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_starts_at_min_datetime() {
+        let clock = FixedClock(datetime!(2030-01-01 03:00 UTC));
+        let config = PickerConfigBuilder::default()
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2060-01-01 00:00))
+                    .disabled_years((2040..=2059).collect())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2060-01-01 10:00)),
+            config.guess_allowed_year_month_with(&clock)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_skips_forbidden_year_group() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-01-01 00:00))
+            .disabled_years((2024..=2059).collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2060-01-01 10:00)),
+            guess_with_constraints(date_constraints)
+        );
+    }
+
+    #[test]
+    fn guess_allowed_year_month_without_bounds_stops_after_max_searched_years() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .disabled_years((1900..=2200).collect())
+            .build()
+            .unwrap();
+        assert_eq!(None, guess_with_constraints(date_constraints));
+    }
+
+    #[test]
+    fn guess_allowed_year_month_everything_forbidden() {
+        let date_constraints = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-05-01 00:00))
+            .max_datetime(datetime!(2024-05-31 00:00))
+            .disabled_months([Month::May].into_iter().collect())
+            .build()
+            .unwrap();
+        assert_eq!(None, guess_with_constraints(date_constraints));
+    }
+
+    #[test]
+    fn grid_options_from_config() {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .first_weekday(Weekday::Monday)
+            .six_weeks(true)
+            .week_numbering(WeekNumbering::Us)
+            .build()
+            .unwrap();
+        let options = config.grid_options().build().unwrap();
+        assert_eq!(WeekNumbering::Us, *options.week_numbering());
+        assert_eq!(Weekday::Monday, *options.first_weekday());
+        assert!(*options.six_weeks());
+
+        let options = PickerConfig::<DateConstraints>::default()
+            .grid_options()
+            .build()
+            .unwrap();
+        assert_eq!(Weekday::Sunday, *options.first_weekday());
+        assert!(!*options.six_weeks());
+    }
+
+    #[test]
+    fn is_year_forbidden_in_fiscal_calendar() {
+        let builder = PickerConfigBuilder::default()
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2024-10-01 00:00))
+                    .build()
+                    .unwrap(),
+            )
+            .clone();
+        let calendar_year = builder.clone().build().unwrap();
+        assert!(!calendar_year.is_year_forbidden(&datetime!(2024-03-01 00:00)));
+
+        // the fiscal year 2024 ends on 30 September 2024
+        let fiscal_year = builder
+            .clone()
+            .fiscal_calendar(FiscalCalendar::THAI_GOVERNMENT)
+            .build()
+            .unwrap();
+        assert!(fiscal_year.is_year_forbidden(&datetime!(2024-03-01 00:00)));
+        assert!(!fiscal_year.is_year_forbidden(&datetime!(2024-10-01 00:00)));
+    }
+
+    #[test]
+    fn is_year_group_forbidden_in_fiscal_calendar() {
+        let builder = PickerConfigBuilder::default()
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2039-10-01 00:00))
+                    .build()
+                    .unwrap(),
+            )
+            .clone();
+        let calendar_year = builder.clone().build().unwrap();
+        assert!(!calendar_year.is_year_group_forbidden(&datetime!(2024-03-01 00:00)));
+
+        // the fiscal years 2020 to 2039 end on 30 September 2039 at the latest
+        let fiscal_year = builder
+            .clone()
+            .fiscal_calendar(FiscalCalendar::THAI_GOVERNMENT)
+            .build()
+            .unwrap();
+        assert!(fiscal_year.is_year_group_forbidden(&datetime!(2024-03-01 00:00)));
+        assert!(!fiscal_year.is_year_group_forbidden(&datetime!(2039-10-01 00:00)));
+    }
+
+    #[test]
+    fn is_quarter_and_half_year_forbidden_in_fiscal_calendar() {
+        let builder = PickerConfigBuilder::default()
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .disabled_months(
+                        [Month::October, Month::November, Month::December]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .disabled_years([2025].into_iter().collect())
+                    .build()
+                    .unwrap(),
+            )
+            .clone();
+        let calendar_year = builder.clone().build().unwrap();
+        assert!(!calendar_year.is_half_year_forbidden(&datetime!(2024-11-01 00:00)));
+
+        // the first half of the fiscal year 2025 is from October 2024 to March 2025
+        let fiscal_year = builder
+            .clone()
+            .fiscal_calendar(FiscalCalendar::THAI_GOVERNMENT)
+            .build()
+            .unwrap();
+        assert!(fiscal_year.is_quarter_forbidden(&datetime!(2024-11-01 00:00)));
+        assert!(!fiscal_year.is_quarter_forbidden(&datetime!(2024-09-01 00:00)));
+        assert!(fiscal_year.is_half_year_forbidden(&datetime!(2024-11-01 00:00)));
+        assert!(!fiscal_year.is_half_year_forbidden(&datetime!(2024-09-01 00:00)));
+    }
+
+    #[test]
+    fn picker_config_minute_step_not_divisor_of_60() {
+        for minute_step in [0, 7, 45] {
+            let config = PickerConfigBuilder::<DateConstraints>::default()
+                .minute_step(minute_step)
+                .build();
+            assert_eq!(
+                config.unwrap_err(),
+                ConfigError::InvalidMinuteStep { minute_step }
+            );
+        }
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .minute_step(15)
+            .build();
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn allowed_time_slots_with_minute_step() {
+        let config = PickerConfigBuilder::default()
+            .minute_step(30)
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .max_datetime(datetime!(2024-05-15 01:00))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            vec![
+                datetime!(2024-05-15 00:00),
+                datetime!(2024-05-15 00:30),
+                datetime!(2024-05-15 01:00)
+            ],
+            config.allowed_time_slots(&datetime!(2024-05-15 12:00))
+        );
     }
 }
@@ -0,0 +1,622 @@
+//! Combinators composing date constraints, usable as the date constraints of a `PickerConfig`.
+//!
+//! Lists of constraints of different types are supported by `PickerConfig` as
+//! `Vec<Arc<dyn HasDateConstraints + Send + Sync>>`, since its date constraints must implement
+//! `Clone` and `Default`. `Vec<Box<dyn HasDateConstraints>>` also implements `HasDateConstraints`,
+//! but only for a direct use, as `Box<dyn HasDateConstraints>` can not be cloned.
+
+use std::{fmt, sync::Arc};
+
+use time::{Date, Duration, PrimitiveDateTime, Time};
+
+use crate::config::date_constraints::{
+    HasDateConstraints, LAST_SECOND, is_every_day_forbidden, is_every_month_forbidden,
+    is_every_year_forbidden,
+};
+
+/// Date constraints allowing a datetime only if both constraints allow it.
+/// A day, month or year is forbidden if one of the constraints forbids it entirely,
+/// or if every day of the month or year is forbidden by one of them.
+/// A day allowed by both constraints is checked at the `time_boundaries` of both,
+/// or minute by minute if one of them does not know its boundaries,
+/// as their allowed times can be disjoint.
+#[derive(Debug, Default, Clone)]
+pub struct And<A, B>(pub A, pub B);
+
+/// Date constraints allowing a datetime if one of the constraints allows it.
+#[derive(Debug, Default, Clone)]
+pub struct Or<A, B>(pub A, pub B);
+
+/// Date constraints allowing exactly the datetimes forbidden by the inner constraints,
+/// days are checked at the `time_boundaries` of the inner constraints, or minute by minute.
+#[derive(Debug, Default, Clone)]
+pub struct Not<A>(pub A);
+
+type DatetimeRule = Arc<dyn Fn(&PrimitiveDateTime) -> bool + Send + Sync>;
+type DayRule = Arc<dyn Fn(&Date) -> bool + Send + Sync>;
+type BoundariesRule = Arc<dyn Fn(&Date) -> Vec<Time> + Send + Sync>;
+
+#[derive(Clone)]
+enum Rule {
+    Datetimes(DatetimeRule, Option<BoundariesRule>),
+    Days(DayRule),
+}
+
+/// Date constraints given by a closure returning true for the forbidden values,
+/// the default `FnConstraint` forbids nothing.
+#[derive(Default, Clone)]
+pub struct FnConstraint {
+    rule: Option<Rule>,
+}
+
+impl FnConstraint {
+    /// Forbids the datetimes for which the closure returns true, days are checked minute by minute,
+    /// so the closure is called up to 1440 times for each day of a month, year or year group checked,
+    /// see `datetimes_with_boundaries` to check only the times at which the result changes
+    pub fn datetimes<F>(is_forbidden: F) -> Self
+    where
+        F: Fn(&PrimitiveDateTime) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            rule: Some(Rule::Datetimes(Arc::new(is_forbidden), None)),
+        }
+    }
+
+    /// Forbids the datetimes for which the first closure returns true, days are checked only
+    /// at midnight, at the last second and at the times returned by the second closure for the day,
+    /// which must include every time at which a range of forbidden or allowed times starts
+    pub fn datetimes_with_boundaries<F, B>(is_forbidden: F, boundaries: B) -> Self
+    where
+        F: Fn(&PrimitiveDateTime) -> bool + Send + Sync + 'static,
+        B: Fn(&Date) -> Vec<Time> + Send + Sync + 'static,
+    {
+        Self {
+            rule: Some(Rule::Datetimes(
+                Arc::new(is_forbidden),
+                Some(Arc::new(boundaries)),
+            )),
+        }
+    }
+
+    /// Forbids the entire days for which the closure returns true
+    pub fn days<F>(is_forbidden: F) -> Self
+    where
+        F: Fn(&Date) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            rule: Some(Rule::Days(Arc::new(is_forbidden))),
+        }
+    }
+}
+
+impl fmt::Debug for FnConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rule = match self.rule {
+            Some(Rule::Datetimes(_, None)) => "datetimes",
+            Some(Rule::Datetimes(_, Some(_))) => "datetimes with boundaries",
+            Some(Rule::Days(_)) => "days",
+            None => "none",
+        };
+        f.debug_struct("FnConstraint").field("rule", &rule).finish()
+    }
+}
+
+/// Returns true if every time of the day including the given datetime is forbidden,
+/// checking the `time_boundaries` of the constraints if known, otherwise every minute
+fn is_every_time_forbidden<T: HasDateConstraints + ?Sized>(
+    config: &T,
+    day: &PrimitiveDateTime,
+) -> bool {
+    let midnight = day.replace_time(Time::MIDNIGHT);
+    match config.time_boundaries(day) {
+        // the forbidden times change only at the boundaries, the first range starts at midnight
+        Some(boundaries) => [Time::MIDNIGHT, LAST_SECOND]
+            .into_iter()
+            .chain(boundaries)
+            .all(|time| config.is_datetime_forbidden(&midnight.replace_time(time))),
+        None => (0..24 * 60)
+            .all(|minute| config.is_datetime_forbidden(&(midnight + Duration::minutes(minute)))),
+    }
+}
+
+/// Returns the boundaries of both constraints, None if one of them is unknown
+fn both_time_boundaries(a: Option<Vec<Time>>, b: Option<Vec<Time>>) -> Option<Vec<Time>> {
+    let mut boundaries = a?;
+    boundaries.extend(b?);
+    Some(boundaries)
+}
+
+/// Returns the earlier of the given upper bounds, None if both are unbounded
+fn earlier_max_bound(
+    a: Option<PrimitiveDateTime>,
+    b: Option<PrimitiveDateTime>,
+) -> Option<PrimitiveDateTime> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+impl<A: HasDateConstraints, B: HasDateConstraints> HasDateConstraints for And<A, B> {
+    fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.0.is_datetime_forbidden(date) || self.1.is_datetime_forbidden(date)
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.0.is_day_forbidden(date)
+            || self.1.is_day_forbidden(date)
+            || is_every_time_forbidden(self, date)
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+        self.0.is_month_forbidden(year_month_info)
+            || self.1.is_month_forbidden(year_month_info)
+            || is_every_day_forbidden(self, year_month_info)
+    }
+
+    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.0.is_year_forbidden(year)
+            || self.1.is_year_forbidden(year)
+            || is_every_month_forbidden(self, year)
+    }
+
+    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.0.is_year_group_forbidden(year)
+            || self.1.is_year_group_forbidden(year)
+            || is_every_year_forbidden(self, year)
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        both_time_boundaries(self.0.time_boundaries(day), self.1.time_boundaries(day))
+    }
+
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        self.0.min_bound().max(self.1.min_bound())
+    }
+
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        earlier_max_bound(self.0.max_bound(), self.1.max_bound())
+    }
+}
+
+impl<A: HasDateConstraints, B: HasDateConstraints> HasDateConstraints for Or<A, B> {
+    fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.0.is_datetime_forbidden(date) && self.1.is_datetime_forbidden(date)
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.0.is_day_forbidden(date) && self.1.is_day_forbidden(date)
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+        self.0.is_month_forbidden(year_month_info) && self.1.is_month_forbidden(year_month_info)
+    }
+
+    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.0.is_year_forbidden(year) && self.1.is_year_forbidden(year)
+    }
+
+    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.0.is_year_group_forbidden(year) && self.1.is_year_group_forbidden(year)
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        both_time_boundaries(self.0.time_boundaries(day), self.1.time_boundaries(day))
+    }
+
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        Some(self.0.min_bound()?.min(self.1.min_bound()?))
+    }
+
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        Some(self.0.max_bound()?.max(self.1.max_bound()?))
+    }
+}
+
+impl<A: HasDateConstraints> HasDateConstraints for Not<A> {
+    fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        !self.0.is_datetime_forbidden(date)
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        !self.0.is_day_forbidden(date) && is_every_time_forbidden(self, date)
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        self.0.time_boundaries(day)
+    }
+}
+
+impl HasDateConstraints for FnConstraint {
+    fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        match &self.rule {
+            Some(Rule::Datetimes(is_forbidden, _)) => is_forbidden(date),
+            Some(Rule::Days(is_forbidden)) => is_forbidden(&date.date()),
+            None => false,
+        }
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        match &self.rule {
+            Some(Rule::Datetimes(..)) => is_every_time_forbidden(self, date),
+            Some(Rule::Days(is_forbidden)) => is_forbidden(&date.date()),
+            None => false,
+        }
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        match &self.rule {
+            Some(Rule::Datetimes(_, boundaries)) => boundaries
+                .as_ref()
+                .map(|boundaries| boundaries(&day.date())),
+            Some(Rule::Days(_)) | None => Some(Vec::new()),
+        }
+    }
+}
+
+/// Date constraints allowing a datetime only if every constraint of the list allows it,
+/// like `And`, an empty list forbids nothing.
+impl<T: HasDateConstraints> HasDateConstraints for Vec<T> {
+    fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.iter().any(|config| config.is_datetime_forbidden(date))
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.iter().any(|config| config.is_day_forbidden(date))
+            || (self.len() > 1 && is_every_time_forbidden(self, date))
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+        self.iter()
+            .any(|config| config.is_month_forbidden(year_month_info))
+            || (!self.is_empty() && is_every_day_forbidden(self, year_month_info))
+    }
+
+    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.iter().any(|config| config.is_year_forbidden(year))
+            || (!self.is_empty() && is_every_month_forbidden(self, year))
+    }
+
+    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.iter()
+            .any(|config| config.is_year_group_forbidden(year))
+            || (!self.is_empty() && is_every_year_forbidden(self, year))
+    }
+
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        self.iter()
+            .map(|config| config.time_boundaries(day))
+            .fold(Some(Vec::new()), both_time_boundaries)
+    }
+
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        self.iter().filter_map(|config| config.min_bound()).max()
+    }
+
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        self.iter().filter_map(|config| config.max_bound()).min()
+    }
+}
+
+macro_rules! impl_for_pointer {
+    ($pointer:ident) => {
+        impl<T: HasDateConstraints + ?Sized> HasDateConstraints for $pointer<T> {
+            fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+                (**self).is_datetime_forbidden(date)
+            }
+
+            fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+                (**self).is_day_forbidden(date)
+            }
+
+            fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+                (**self).is_month_forbidden(year_month_info)
+            }
+
+            fn is_hour_forbidden(&self, hour: &PrimitiveDateTime) -> bool {
+                (**self).is_hour_forbidden(hour)
+            }
+
+            fn is_week_forbidden(&self, week_start: &PrimitiveDateTime) -> bool {
+                (**self).is_week_forbidden(week_start)
+            }
+
+            fn is_quarter_forbidden(&self, quarter: &PrimitiveDateTime) -> bool {
+                (**self).is_quarter_forbidden(quarter)
+            }
+
+            fn is_half_year_forbidden(&self, half_year: &PrimitiveDateTime) -> bool {
+                (**self).is_half_year_forbidden(half_year)
+            }
+
+            fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+                (**self).is_year_forbidden(year)
+            }
+
+            fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+                (**self).is_year_group_forbidden(year)
+            }
+
+            fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+                (**self).time_boundaries(day)
+            }
+
+            fn min_bound(&self) -> Option<PrimitiveDateTime> {
+                (**self).min_bound()
+            }
+
+            fn max_bound(&self) -> Option<PrimitiveDateTime> {
+                (**self).max_bound()
+            }
+        }
+    };
+}
+
+impl_for_pointer!(Box);
+impl_for_pointer!(Arc);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        PickerConfigBuilder,
+        date_constraints::{DateConstraints, DateConstraintsBuilder, TimeWindow},
+    };
+
+    use rstest::*;
+    use std::{
+        cell::Cell,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use time::{
+        Month, Weekday,
+        macros::{datetime, time},
+    };
+
+    fn create_weekend() -> DateConstraints {
+        DateConstraintsBuilder::default()
+            .disabled_weekdays([Weekday::Saturday, Weekday::Sunday].into_iter().collect())
+            .build()
+            .unwrap()
+    }
+
+    fn create_before_june() -> DateConstraints {
+        DateConstraintsBuilder::default()
+            .max_datetime(datetime!(2024-05-31 23:59))
+            .build()
+            .unwrap()
+    }
+
+    #[rstest(
+        expected_and, expected_or, date, //
+        case::both_allow(false, false, datetime!(2024-05-15 10:00)),
+        case::first_forbids(true, false, datetime!(2024-05-18 10:00)),
+        case::second_forbids(true, false, datetime!(2024-06-03 10:00)),
+        case::both_forbid(true, true, datetime!(2024-06-01 10:00)),
+    )]
+    fn and_or_days(expected_and: bool, expected_or: bool, date: PrimitiveDateTime) {
+        let and = And(create_weekend(), create_before_june());
+        let or = Or(create_weekend(), create_before_june());
+        assert_eq!(expected_and, and.is_datetime_forbidden(&date));
+        assert_eq!(expected_and, and.is_day_forbidden(&date));
+        assert_eq!(expected_or, or.is_datetime_forbidden(&date));
+        assert_eq!(expected_or, or.is_day_forbidden(&date));
+    }
+
+    #[test]
+    fn and_month_forbidden_by_both() {
+        let first_half = FnConstraint::days(|date| date.day() <= 15);
+        let second_half = FnConstraint::days(|date| date.day() > 15);
+        let and = And(first_half.clone(), second_half.clone());
+        assert!(!first_half.is_month_forbidden(&datetime!(2024-05-01 00:00)));
+        assert!(and.is_month_forbidden(&datetime!(2024-05-01 00:00)));
+        assert!(!Or(first_half, second_half).is_month_forbidden(&datetime!(2024-05-01 00:00)));
+    }
+
+    #[test]
+    fn and_disjoint_times() {
+        let morning = FnConstraint::datetimes(|datetime| !(9..12).contains(&datetime.hour()));
+        let afternoon = FnConstraint::datetimes(|datetime| !(13..17).contains(&datetime.hour()));
+        assert!(!morning.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(!afternoon.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(
+            And(morning.clone(), afternoon.clone()).is_day_forbidden(&datetime!(2024-05-15 00:00))
+        );
+        assert!(
+            vec![morning.clone(), afternoon.clone()].is_day_forbidden(&datetime!(2024-05-15 00:00))
+        );
+        assert!(!Or(morning, afternoon).is_day_forbidden(&datetime!(2024-05-15 00:00)));
+    }
+
+    fn create_open_between(start: Time, end: Time) -> DateConstraints {
+        DateConstraintsBuilder::default()
+            .allowed_time_windows(
+                [(Weekday::Wednesday, vec![TimeWindow::new(start, end)])]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[rstest(
+        expected, first, second, //
+        case::disjoint_windows(true, create_open_between(time!(09:00), time!(12:00)), create_open_between(time!(13:00), time!(17:00))),
+        case::overlapping_windows(false, create_open_between(time!(09:00), time!(12:00)), create_open_between(time!(11:30), time!(17:00))),
+        case::touching_windows(true, create_open_between(time!(09:00), time!(12:00)), create_open_between(time!(12:00), time!(17:00))),
+    )]
+    fn and_time_windows(expected: bool, first: DateConstraints, second: DateConstraints) {
+        assert_eq!(
+            expected,
+            And(first.clone(), second.clone()).is_day_forbidden(&datetime!(2024-05-15 00:00))
+        );
+        assert_eq!(
+            expected,
+            vec![first, second].is_day_forbidden(&datetime!(2024-05-15 00:00))
+        );
+    }
+
+    #[rstest(
+        expected, date, //
+        case::before_disabled_range(false, datetime!(2024-05-15 00:00)),
+        case::covered_by_disabled_range(true, datetime!(2024-05-22 00:00)),
+        case::after_disabled_range(false, datetime!(2024-05-29 00:00)),
+    )]
+    fn and_disabled_ranges(expected: bool, date: PrimitiveDateTime) {
+        // the windows of the Wednesdays end when the range ends, at noon
+        let range = DateConstraintsBuilder::default()
+            .disabled_ranges(vec![
+                datetime!(2024-05-15 12:00)..=datetime!(2024-05-22 11:59:59),
+            ])
+            .build()
+            .unwrap();
+        let and = And(range, create_open_between(time!(00:00), time!(12:00)));
+        assert_eq!(expected, and.is_day_forbidden(&date));
+    }
+
+    /// Counts the checked datetimes of the inner constraints
+    struct Counting<T> {
+        inner: T,
+        checked_datetimes: Cell<usize>,
+    }
+
+    impl<T: HasDateConstraints> HasDateConstraints for Counting<T> {
+        fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            self.checked_datetimes.set(self.checked_datetimes.get() + 1);
+            self.inner.is_datetime_forbidden(date)
+        }
+
+        fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            self.inner.is_day_forbidden(date)
+        }
+
+        fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+            self.inner.time_boundaries(day)
+        }
+    }
+
+    #[test]
+    fn and_checks_only_time_boundaries() {
+        let counting = |inner| Counting {
+            inner,
+            checked_datetimes: Cell::new(0),
+        };
+        let and = And(
+            counting(create_open_between(time!(09:00), time!(12:00))),
+            counting(create_open_between(time!(13:00), time!(17:00))),
+        );
+        assert!(and.is_year_forbidden(&datetime!(2024-01-01 00:00)));
+        // at most 2 times and 4 window boundaries of each Wednesday, instead of every minute
+        let checked_datetimes = and.0.checked_datetimes.get() + and.1.checked_datetimes.get();
+        assert!(checked_datetimes <= 2 * 53 * 6);
+    }
+
+    #[test]
+    fn bounds() {
+        let from_april = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-04-01 00:00))
+            .build()
+            .unwrap();
+        let and = And(from_april.clone(), create_before_june());
+        assert_eq!(Some(datetime!(2024-04-01 00:00)), and.min_bound());
+        assert_eq!(Some(datetime!(2024-05-31 23:59)), and.max_bound());
+        let or = Or(from_april.clone(), create_before_june());
+        assert_eq!(None, or.min_bound());
+        assert_eq!(None, or.max_bound());
+        assert_eq!(
+            Some(datetime!(2024-04-01 00:00)),
+            vec![from_april, create_before_june()].min_bound()
+        );
+        assert_eq!(None, Not(create_before_june()).max_bound());
+    }
+
+    #[rstest(
+        expected, date, //
+        case::weekday(true, datetime!(2024-05-15 10:00)),
+        case::saturday(false, datetime!(2024-05-18 10:00)),
+    )]
+    fn not_weekend(expected: bool, date: PrimitiveDateTime) {
+        let not = Not(create_weekend());
+        assert_eq!(expected, not.is_datetime_forbidden(&date));
+        assert_eq!(expected, not.is_day_forbidden(&date));
+    }
+
+    #[test]
+    fn not_partly_forbidden_day() {
+        let not = Not(DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-05-15 12:00))
+            .build()
+            .unwrap());
+        assert!(!not.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(not.is_day_forbidden(&datetime!(2024-05-16 00:00)));
+        assert!(!not.is_month_forbidden(&datetime!(2024-05-01 00:00)));
+        assert!(not.is_month_forbidden(&datetime!(2024-06-01 00:00)));
+    }
+
+    #[rstest(
+        expected_datetime, expected_day, date, //
+        case::morning(true, false, datetime!(2024-05-15 10:00)),
+        case::afternoon(false, false, datetime!(2024-05-15 14:00)),
+    )]
+    fn fn_constraint_datetimes(
+        expected_datetime: bool,
+        expected_day: bool,
+        date: PrimitiveDateTime,
+    ) {
+        let config = FnConstraint::datetimes(|date| date.hour() < 12);
+        assert_eq!(expected_datetime, config.is_datetime_forbidden(&date));
+        assert_eq!(expected_day, config.is_day_forbidden(&date));
+    }
+
+    #[test]
+    fn fn_constraint_datetimes_with_boundaries() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted_calls = calls.clone();
+        let business_hours = FnConstraint::datetimes_with_boundaries(
+            move |datetime| {
+                counted_calls.fetch_add(1, Ordering::Relaxed);
+                datetime.weekday() == Weekday::Sunday || !(9..17).contains(&datetime.hour())
+            },
+            |_| vec![time!(09:00), time!(17:00)],
+        );
+        assert!(!business_hours.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(business_hours.is_day_forbidden(&datetime!(2024-05-19 00:00)));
+        assert!(!business_hours.is_year_group_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(calls.load(Ordering::Relaxed) < 100);
+    }
+
+    #[test]
+    fn fn_constraint_days() {
+        let config = FnConstraint::days(|date| date.month() == Month::May);
+        assert!(config.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(config.is_month_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(!config.is_month_forbidden(&datetime!(2024-06-15 00:00)));
+        assert!(!FnConstraint::default().is_year_forbidden(&datetime!(2024-06-15 00:00)));
+    }
+
+    #[test]
+    fn boxed_list() {
+        let list: Vec<Box<dyn HasDateConstraints>> =
+            vec![Box::new(create_weekend()), Box::new(create_before_june())];
+        assert!(!list.is_day_forbidden(&datetime!(2024-05-15 00:00)));
+        assert!(list.is_day_forbidden(&datetime!(2024-05-18 00:00)));
+        assert!(list.is_month_forbidden(&datetime!(2024-06-01 00:00)));
+        assert!(!Vec::<DateConstraints>::new().is_year_forbidden(&datetime!(2024-06-01 00:00)));
+    }
+
+    #[test]
+    fn picker_config_with_composed_constraints() {
+        let list: Vec<Arc<dyn HasDateConstraints + Send + Sync>> = vec![
+            Arc::new(create_before_june()),
+            Arc::new(FnConstraint::days(|date| date.day() == 1)),
+        ];
+        let config = PickerConfigBuilder::default()
+            .date_constraints(And(create_weekend(), list))
+            .build()
+            .unwrap();
+        assert!(config.is_day_forbidden(&datetime!(2024-05-18 00:00)));
+        assert!(config.is_day_forbidden(&datetime!(2024-05-01 00:00)));
+        assert!(!config.is_day_forbidden(&datetime!(2024-05-02 00:00)));
+        assert!(config.is_year_forbidden(&datetime!(2025-01-01 00:00)));
+    }
+}
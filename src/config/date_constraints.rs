@@ -1,16 +1,28 @@
 use derive_builder::Builder;
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
 use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
 
 use crate::{
-    utils::from_ymdhm,
+    config::{date_ranges::DateTimeRanges, error::ConfigError, recurrence::Recurrence},
+    utils::{from_ymd, from_ymdhm, try_from_ymdhm},
     viewed_date::{ViewedDate, year_group_range},
 };
 
+#[cfg(feature = "serde")]
+use crate::config::serde_formats::{
+    builder_iso_datetime, deserialize_time, option_iso_datetime, serialize_time, sorted_set,
+    sorted_weekday_map, sorted_weekdays,
+};
+
 #[cfg(test)]
 use mockall::automock;
 
-/// Trait that can be implemented to create your own date constraints.
+/// Trait that can be implemented to create your own date constraints,
+/// only `is_datetime_forbidden` and `is_day_forbidden` are required,
+/// the larger scales are derived from the days by default.
 #[cfg_attr(test, automock)]
 pub trait HasDateConstraints {
     /// Returns true if the given datetime is forbidden.
@@ -20,42 +32,215 @@ pub trait HasDateConstraints {
     fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool;
 
     /// Returns true if the entire month described by year_month_info is forbidden.
-    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool;
+    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+        is_every_day_forbidden(self, year_month_info)
+    }
+
+    /// Returns true if every minute of the hour including the given datetime is forbidden.
+    fn is_hour_forbidden(&self, hour: &PrimitiveDateTime) -> bool {
+        let start =
+            hour.replace_time(Time::from_hms(hour.hour(), 0, 0).expect("the hour is valid"));
+        (0..60).all(|minute| self.is_datetime_forbidden(&(start + Duration::minutes(minute))))
+    }
+
+    /// Returns true if the entire week of 7 days starting on the given datetime is forbidden.
+    fn is_week_forbidden(&self, week_start: &PrimitiveDateTime) -> bool {
+        // the days out of the range of `Date` can not be selected
+        (0..7).all(|day| {
+            week_start
+                .checked_add(Duration::days(day))
+                .is_none_or(|day| self.is_day_forbidden(&day))
+        })
+    }
+
+    /// Returns true if the entire quarter including the given datetime is forbidden.
+    fn is_quarter_forbidden(&self, quarter: &PrimitiveDateTime) -> bool {
+        let first_month = quarter.first_day_of_quarter();
+        [
+            first_month,
+            first_month.next_month(),
+            first_month.next_month().next_month(),
+        ]
+        .iter()
+        .all(|month| self.is_month_forbidden(month))
+    }
+
+    /// Returns true if the entire half year including the given datetime is forbidden.
+    fn is_half_year_forbidden(&self, half_year: &PrimitiveDateTime) -> bool {
+        let first_quarter = half_year.first_day_of_half_year();
+        self.is_quarter_forbidden(&first_quarter)
+            && self.is_quarter_forbidden(&first_quarter.next_quarter())
+    }
 
     /// Returns true if the entire given year is forbidden.
-    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool;
+    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        is_every_month_forbidden(self, year)
+    }
 
     /// Returns true if the entire group of years including the given year is forbidden.
     /// A group of years are inclusive intervals [1980, 1999], [2000, 2019], [2020, 2039], ...
-    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool;
+    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        is_every_year_forbidden(self, year)
+    }
+
+    /// Returns the times of the day of the given datetime at which a range of forbidden times
+    /// may start or end, None if unknown. `And`, `Not` and lists of constraints check only
+    /// these times of a day instead of every minute.
+    fn time_boundaries(&self, _day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        None
+    }
+
+    /// Returns a datetime before which every datetime is forbidden, if such is known.
+    /// Bounds the search of the nearest allowed day.
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        None
+    }
+
+    /// Returns a datetime after which every datetime is forbidden, if such is known.
+    /// Bounds the search of the nearest allowed day.
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        None
+    }
+}
+
+/// Returns true if every day of the month including the given datetime is forbidden
+pub(crate) fn is_every_day_forbidden<T: HasDateConstraints + ?Sized>(
+    config: &T,
+    month: &PrimitiveDateTime,
+) -> bool {
+    month
+        .dates_in_month()
+        .iter()
+        .all(|day| config.is_day_forbidden(day))
+}
+
+/// Returns true if every month of the year including the given datetime is forbidden
+pub(crate) fn is_every_month_forbidden<T: HasDateConstraints + ?Sized>(
+    config: &T,
+    year: &PrimitiveDateTime,
+) -> bool {
+    (1..=12u8).all(|month| {
+        config.is_month_forbidden(&from_ymdhm(
+            year.year(),
+            month,
+            1,
+            year.hour(),
+            year.minute(),
+        ))
+    })
+}
+
+/// Returns true if every year of the year group including the given datetime is forbidden
+pub(crate) fn is_every_year_forbidden<T: HasDateConstraints + ?Sized>(
+    config: &T,
+    year: &PrimitiveDateTime,
+) -> bool {
+    // the years out of the range of `Date` can not be selected
+    year_group_range(year.year()).all(|y| {
+        try_from_ymdhm(y, 1, 1, year.hour(), year.minute())
+            .ok()
+            .is_none_or(|first_day| config.is_year_forbidden(&first_day))
+    })
+}
+
+/// Last second of a day, the end of the days, months and years covered by `disabled_ranges`
+pub(crate) const LAST_SECOND: Time = time::macros::time!(23:59:59);
+
+/// Recurring range of times of a day, from `start` inclusive to `end` exclusive,
+/// an `end` at midnight includes the rest of the day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeWindow {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_time",
+            deserialize_with = "deserialize_time"
+        )
+    )]
+    start: Time,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_time",
+            deserialize_with = "deserialize_time"
+        )
+    )]
+    end: Time,
+}
+
+impl TimeWindow {
+    pub const fn new(start: Time, end: Time) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the first time of the window
+    pub const fn start(&self) -> Time {
+        self.start
+    }
+
+    /// Returns the time right after the window
+    pub const fn end(&self) -> Time {
+        self.end
+    }
+
+    /// Returns true if the given time is in the window
+    pub fn contains(&self, time: &Time) -> bool {
+        self.start <= *time && (self.end == Time::MIDNIGHT || *time < self.end)
+    }
+
+    /// Returns true if the window includes at least one time
+    pub fn is_valid(&self) -> bool {
+        self.end == Time::MIDNIGHT || self.start < self.end
+    }
 }
 
 /// Date constraints configuration
 #[derive(Default, Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 #[builder(default)]
-#[builder(build_fn(validate = "Self::validate"))]
+#[builder(build_fn(validate = "Self::validate", error = "ConfigError"))]
+#[cfg_attr(feature = "serde", builder(derive(serde::Deserialize)))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DateConstraintsBuilder")
+)]
 pub struct DateConstraints {
     /// Inclusive minimal datetime constraint
     /// the earliest datetime that can be selected
+    #[cfg_attr(feature = "serde", serde(with = "option_iso_datetime"))]
+    #[cfg_attr(
+        feature = "serde",
+        builder_field_attr(serde(default, deserialize_with = "builder_iso_datetime"))
+    )]
     min_datetime: Option<PrimitiveDateTime>,
 
     /// Inclusive maximal datetime constraint
     /// the latest datetime that can be selected
+    #[cfg_attr(feature = "serde", serde(with = "option_iso_datetime"))]
+    #[cfg_attr(
+        feature = "serde",
+        builder_field_attr(serde(default, deserialize_with = "builder_iso_datetime"))
+    )]
     max_datetime: Option<PrimitiveDateTime>,
 
     /// Disabled weekdays, that should not be selectable
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_weekdays"))]
     disabled_weekdays: HashSet<Weekday>,
 
     /// Entire completely disabled months in every year
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_set"))]
     disabled_months: HashSet<Month>,
 
     /// Entire completely disabled years
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_set"))]
     disabled_years: HashSet<i32>,
 
     /// Disabled monthly periodically repeating dates, so it is just a day number
     /// starting from 1 for the first day of the month
     /// if unique dates in a certain year should not be selectable use `disabled_unique_dates`
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_set"))]
     disabled_monthly_dates: HashSet<u8>,
 
     /// Disabled yearly periodically repeating dates that should not be selectable,
@@ -65,15 +250,77 @@ pub struct DateConstraints {
 
     /// Disabled unique dates with a specific year, month and day that should not be selectable,
     /// if some periodically repeated dates should not be selectable use the correct option
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_set"))]
     disabled_unique_dates: HashSet<Date>,
+
+    /// Disabled recurring dates, like the last Friday of every month
+    disabled_recurrences: Vec<Recurrence>,
+
+    /// Disabled inclusive ranges of datetimes, like maintenance windows or school holidays
+    #[builder(setter(into))]
+    disabled_ranges: DateTimeRanges,
+
+    /// Enabled unique dates, if not empty every other date is forbidden
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_set"))]
+    enabled_unique_dates: HashSet<Date>,
+
+    /// Enabled weekdays, if not empty every other weekday is forbidden
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_weekdays"))]
+    enabled_weekdays: HashSet<Weekday>,
+
+    /// Enabled inclusive ranges of dates, if not empty every date outside of the ranges is forbidden.
+    /// A date must be included in every non-empty `enabled_*` option to be allowed
+    enabled_date_ranges: Vec<RangeInclusive<Date>>,
+
+    /// Allowed times of each weekday, like business hours,
+    /// if not empty the times outside of the windows and the weekdays without windows are forbidden
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_weekday_map"))]
+    allowed_time_windows: HashMap<Weekday, Vec<TimeWindow>>,
+
+    /// Forbidden times of each weekday, like a lunch break
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted_weekday_map"))]
+    disabled_time_windows: HashMap<Weekday, Vec<TimeWindow>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DateConstraintsBuilder> for DateConstraints {
+    type Error = ConfigError;
+
+    /// Builds the deserialized builder, to validate the deserialized date constraints
+    fn try_from(builder: DateConstraintsBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
 }
 
 impl DateConstraintsBuilder {
-    pub fn validate(&self) -> Result<(), String> {
-        if let (Some(min_datetime), Some(max_datetime)) = (self.min_datetime, self.max_datetime) {
-            if min_datetime > max_datetime {
-                return Err("min_datetime must be earlier or exactly at max_datetime".into());
-            }
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let (Some(Some(min)), Some(Some(max))) = (self.min_datetime, self.max_datetime)
+            && min > max
+        {
+            return Err(ConfigError::MinAfterMax { min, max });
+        }
+        if let Some(range) = self
+            .enabled_date_ranges
+            .iter()
+            .flatten()
+            .find(|range| range.start() > range.end())
+        {
+            return Err(ConfigError::EnabledRangeReversed {
+                start: *range.start(),
+                end: *range.end(),
+            });
+        }
+        if let Some((weekday, window)) = [&self.allowed_time_windows, &self.disabled_time_windows]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .flat_map(|(weekday, windows)| windows.iter().map(move |window| (weekday, window)))
+            .find(|(_, window)| !window.is_valid())
+        {
+            return Err(ConfigError::EmptyTimeWindow {
+                weekday: *weekday,
+                window: *window,
+            });
         }
         Ok(())
     }
@@ -91,14 +338,40 @@ cfg_if::cfg_if! {
     }
 }
 
+impl DateConstraints {
+    /// Returns true if the given date is missing from one of the non-empty `enabled_*` options
+    fn is_not_enabled(&self, date: &Date) -> bool {
+        (!self.enabled_unique_dates.is_empty() && !self.enabled_unique_dates.contains(date))
+            || (!self.enabled_weekdays.is_empty()
+                && !self.enabled_weekdays.contains(&date.weekday()))
+            || (!self.enabled_date_ranges.is_empty()
+                && !self
+                    .enabled_date_ranges
+                    .iter()
+                    .any(|range| range.contains(date)))
+    }
+
+    /// Returns true if the given time of the given weekday is outside of the `allowed_time_windows`
+    /// or inside of the `disabled_time_windows`
+    fn is_time_forbidden(&self, weekday: Weekday, time: &Time) -> bool {
+        let in_windows = |windows: &HashMap<Weekday, Vec<TimeWindow>>| {
+            windows
+                .get(&weekday)
+                .is_some_and(|windows| windows.iter().any(|window| window.contains(time)))
+        };
+        (!self.allowed_time_windows.is_empty() && !in_windows(&self.allowed_time_windows))
+            || in_windows(&self.disabled_time_windows)
+    }
+}
+
 impl HasDateConstraints for DateConstraints {
     fn is_datetime_forbidden(&self, datetime: &PrimitiveDateTime) -> bool {
         let date = datetime.date();
         self.min_datetime
-            .map_or(false, |min_datetime| &min_datetime > datetime)
+            .is_some_and(|min_datetime| &min_datetime > datetime)
             || self
                 .max_datetime
-                .map_or(false, |max_datetime| &max_datetime < datetime)
+                .is_some_and(|max_datetime| &max_datetime < datetime)
             || self.disabled_weekdays.contains(&date.weekday())
             || self.disabled_months.contains(&date.month())
             || self.disabled_years.contains(&date.year())
@@ -108,54 +381,114 @@ impl HasDateConstraints for DateConstraints {
                 .disabled_yearly_dates
                 .iter()
                 .any(|disabled| disabled.day() == date.day() && disabled.month() == date.month())
+            || self
+                .disabled_recurrences
+                .iter()
+                .any(|recurrence| recurrence.matches(&date))
+            || self.disabled_ranges.contains(datetime)
+            || self.is_not_enabled(&date)
+            || self.is_time_forbidden(date.weekday(), &datetime.time())
     }
 
     fn is_day_forbidden(&self, datetime: &PrimitiveDateTime) -> bool {
-        let min = PrimitiveDateTime::new(datetime.date(), Time::MIDNIGHT);
-        let max = PrimitiveDateTime::new(
-            datetime.date().next_day().unwrap_or(Date::MAX),
-            Time::MIDNIGHT,
-        ) - Duration::seconds(1);
-        self.is_datetime_forbidden(&min) && self.is_datetime_forbidden(&max)
+        let date = datetime.date();
+        let min = PrimitiveDateTime::new(date, Time::MIDNIGHT);
+        let max = PrimitiveDateTime::new(date, LAST_SECOND);
+        if self.disabled_ranges.covers(&min, &max) {
+            return true;
+        }
+        // the allowed times of the day start at one of these times
+        let range_ends = self
+            .disabled_ranges
+            .ends_between(&min, &max)
+            .filter_map(|end| end.checked_add(Duration::SECOND))
+            .filter(|datetime| datetime.date() == date);
+        let window_boundaries = self
+            .allowed_time_windows
+            .get(&date.weekday())
+            .into_iter()
+            .flatten()
+            .map(TimeWindow::start)
+            .chain(
+                self.disabled_time_windows
+                    .get(&date.weekday())
+                    .into_iter()
+                    .flatten()
+                    .map(TimeWindow::end),
+            )
+            .map(|time| PrimitiveDateTime::new(date, time));
+        [min, max]
+            .into_iter()
+            .chain(
+                self.min_datetime
+                    .filter(|min_datetime| min_datetime.date() == date),
+            )
+            .chain(window_boundaries)
+            .chain(range_ends)
+            .all(|datetime| self.is_datetime_forbidden(&datetime))
     }
 
     fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
         let date = year_month_info.date();
+        let first_day = year_month_info
+            .first_day_of_month()
+            .replace_time(Time::MIDNIGHT);
+        let last_second_of = |day| {
+            PrimitiveDateTime::new(
+                date.replace_day(day).expect("the day is valid"),
+                LAST_SECOND,
+            )
+        };
         self.disabled_years.contains(&date.year())
             || self.disabled_months.contains(&date.month())
-            || year_month_info
-                .dates_in_month()
-                .iter()
-                .all(|datetime| self.is_day_forbidden(&datetime))
+            || self.disabled_ranges.covers(
+                &first_day,
+                &last_second_of(date.month().length(date.year())),
+            )
+            || is_every_day_forbidden(self, year_month_info)
     }
 
     fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
-        let date = year.date();
-        let time = year.time();
-        self.disabled_years.contains(&date.year())
-            || (1..=12u8).all(|month| {
-                self.is_month_forbidden(&from_ymdhm(
-                    date.year(),
-                    month,
-                    date.day(),
-                    time.hour(),
-                    time.minute(),
-                ))
-            })
+        let first_day = PrimitiveDateTime::new(from_ymd(year.year(), 1, 1), Time::MIDNIGHT);
+        let last_second = PrimitiveDateTime::new(from_ymd(year.year(), 12, 31), LAST_SECOND);
+        self.disabled_years.contains(&year.year())
+            || self.disabled_ranges.covers(&first_day, &last_second)
+            || is_every_month_forbidden(self, year)
     }
 
-    fn is_year_group_forbidden(&self, year: &PrimitiveDateTime) -> bool {
-        let date = year.date();
-        let time = year.time();
-        year_group_range(date.year()).all(|year| {
-            self.is_year_forbidden(&from_ymdhm(
-                year,
-                date.month() as u8,
-                date.day(),
-                time.hour(),
-                time.minute(),
-            ))
-        })
+    fn time_boundaries(&self, day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        let date = day.date();
+        let min = PrimitiveDateTime::new(date, Time::MIDNIGHT);
+        let max = PrimitiveDateTime::new(date, LAST_SECOND);
+        // the forbidden times start right after the inclusive ends
+        let after_ends = self
+            .disabled_ranges
+            .ends_between(&min, &max)
+            .chain(self.max_datetime)
+            .filter_map(|end| end.checked_add(Duration::SECOND));
+        let window_boundaries = [&self.allowed_time_windows, &self.disabled_time_windows]
+            .into_iter()
+            .filter_map(|windows| windows.get(&date.weekday()))
+            .flatten()
+            .flat_map(|window| [window.start(), window.end()]);
+        Some(
+            self.disabled_ranges
+                .starts_between(&min, &max)
+                .chain(self.min_datetime)
+                .chain(after_ends)
+                .filter(|datetime| datetime.date() == date)
+                .map(|datetime| datetime.time())
+                .chain(window_boundaries)
+                .collect(),
+        )
+    }
+
+    fn min_bound(&self) -> Option<PrimitiveDateTime> {
+        self.min_datetime
+    }
+
+    fn max_bound(&self) -> Option<PrimitiveDateTime> {
+        self.max_datetime
     }
 }
 
@@ -168,7 +501,7 @@ mod tests {
         viewed_date::{DayNumber, MonthNumber, YearNumber},
     };
     use rstest::*;
-    use time::{Duration, Time};
+    use time::{Duration, Time, macros::time};
 
     #[rstest(
         tested_date,
@@ -210,13 +543,15 @@ mod tests {
     fn picker_config_min_date_greater_than_max_date() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
-            .max_datetime(datetime.clone() - Duration::days(1))
+            .min_datetime(datetime)
+            .max_datetime(datetime - Duration::days(1))
             .build();
-        assert!(config.is_err());
         assert_eq!(
-            config.unwrap_err().to_string(),
-            "min_datetime must be earlier or exactly at max_datetime"
+            config.unwrap_err(),
+            ConfigError::MinAfterMax {
+                min: datetime,
+                max: datetime - Duration::days(1)
+            }
         );
     }
 
@@ -224,8 +559,8 @@ mod tests {
     fn picker_config_min_date_equals_max_date() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
-            .max_datetime(datetime.clone())
+            .min_datetime(datetime)
+            .max_datetime(datetime)
             .build();
         assert!(config.is_ok());
     }
@@ -234,7 +569,7 @@ mod tests {
     fn is_datetime_forbidden_at_min_date_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 23, 55);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
+            .min_datetime(datetime)
             .build()
             .unwrap();
         assert!(!config.is_datetime_forbidden(&datetime))
@@ -244,7 +579,7 @@ mod tests {
     fn is_datetime_forbidden_before_min_date_not_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 23, 55);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
+            .min_datetime(datetime)
             .build()
             .unwrap();
         assert!(config.is_datetime_forbidden(&(datetime - Duration::minutes(1))))
@@ -254,7 +589,7 @@ mod tests {
     fn is_datetime_forbidden_at_max_date_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 23, 55);
         let config = DateConstraintsBuilder::default()
-            .max_datetime(datetime.clone())
+            .max_datetime(datetime)
             .build()
             .unwrap();
         assert!(!config.is_datetime_forbidden(&datetime))
@@ -264,7 +599,7 @@ mod tests {
     fn is_datetime_forbidden_after_max_date_not_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 23, 55);
         let config = DateConstraintsBuilder::default()
-            .max_datetime(datetime.clone())
+            .max_datetime(datetime)
             .build()
             .unwrap();
         assert!(config.is_datetime_forbidden(&(datetime + Duration::minutes(1))))
@@ -274,7 +609,7 @@ mod tests {
     fn is_day_forbidden_at_min_date_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
+            .min_datetime(datetime)
             .build()
             .unwrap();
         assert!(!config.is_day_forbidden(&datetime))
@@ -284,7 +619,7 @@ mod tests {
     fn is_day_forbidden_before_min_date_not_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .min_datetime(datetime.clone())
+            .min_datetime(datetime)
             .build()
             .unwrap();
         assert!(config.is_day_forbidden(&(datetime - Duration::days(1))))
@@ -294,7 +629,7 @@ mod tests {
     fn is_day_forbidden_at_max_date_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .max_datetime(datetime.clone())
+            .max_datetime(datetime)
             .build()
             .unwrap();
         assert!(!config.is_day_forbidden(&datetime))
@@ -304,7 +639,7 @@ mod tests {
     fn is_day_forbidden_after_max_date_not_allowed() {
         let datetime = from_ymdhm(2020, 10, 15, 0, 0);
         let config = DateConstraintsBuilder::default()
-            .max_datetime(datetime.clone())
+            .max_datetime(datetime)
             .build()
             .unwrap();
         assert!(config.is_day_forbidden(&(datetime + Duration::days(1))))
@@ -476,4 +811,406 @@ mod tests {
             .unwrap();
         assert!(config.is_year_group_forbidden(&from_ymdhm(disabled_year_group, 1, 1, 0, 0)))
     }
+
+    #[rstest(
+        tested_year,
+        case::end_of_month(create_datetime(2021, 1, 31, 0, 0)),
+        case::leap_day(create_datetime(2020, 2, 29, 0, 0))
+    )]
+    fn is_year_and_year_group_forbidden_any_day(tested_year: PrimitiveDateTime) {
+        let config = DateConstraints::default();
+        assert!(!config.is_year_forbidden(&tested_year));
+        assert!(!config.is_year_group_forbidden(&tested_year));
+    }
+
+    #[rstest(
+        expected, week_start, //
+        case::weekdays_allowed(false, create_datetime(2024, 5, 13, 0, 0)),
+        case::crosses_max_datetime(false, create_datetime(2024, 5, 27, 0, 0)),
+        case::after_max_datetime(true, create_datetime(2024, 6, 3, 0, 0)),
+    )]
+    fn is_week_forbidden(expected: bool, week_start: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .max_datetime(create_datetime(2024, 5, 31, 0, 0))
+            .disabled_weekdays([Weekday::Saturday, Weekday::Sunday].into_iter().collect())
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_week_forbidden(&week_start));
+    }
+
+    #[rstest(
+        expected_quarter, expected_half_year, tested_date, //
+        case::forbidden_quarter(true, false, create_datetime(2024, 2, 15, 0, 0)),
+        case::allowed_quarter(false, false, create_datetime(2024, 5, 15, 0, 0)),
+        case::forbidden_half_year(true, true, create_datetime(2024, 12, 31, 0, 0)),
+    )]
+    fn is_quarter_and_half_year_forbidden(
+        expected_quarter: bool,
+        expected_half_year: bool,
+        tested_date: PrimitiveDateTime,
+    ) {
+        let config = DateConstraintsBuilder::default()
+            .disabled_months(
+                [
+                    Month::January,
+                    Month::February,
+                    Month::March,
+                    Month::June,
+                    Month::July,
+                    Month::August,
+                    Month::September,
+                    Month::October,
+                    Month::November,
+                    Month::December,
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(expected_quarter, config.is_quarter_forbidden(&tested_date));
+        assert_eq!(
+            expected_half_year,
+            config.is_half_year_forbidden(&tested_date)
+        );
+    }
+
+    #[rstest(
+        expected, tested_hour, //
+        case::before_min_datetime(true, create_datetime(2024, 5, 15, 9, 0)),
+        case::partly_allowed(false, create_datetime(2024, 5, 15, 10, 45)),
+        case::allowed(false, create_datetime(2024, 5, 15, 11, 0)),
+    )]
+    fn is_hour_forbidden(expected: bool, tested_hour: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .min_datetime(create_datetime(2024, 5, 15, 10, 30))
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_hour_forbidden(&tested_hour));
+    }
+
+    fn create_business_hours() -> DateConstraints {
+        let weekdays = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ];
+        let office = TimeWindow::new(time!(08:30), time!(16:30));
+        let mut allowed: HashMap<Weekday, Vec<TimeWindow>> = weekdays
+            .iter()
+            .map(|weekday| (*weekday, vec![office]))
+            .collect();
+        allowed.insert(
+            Weekday::Saturday,
+            vec![TimeWindow::new(time!(09:00), time!(12:00))],
+        );
+        let lunch = TimeWindow::new(time!(12:00), time!(13:00));
+        DateConstraintsBuilder::default()
+            .allowed_time_windows(allowed)
+            .disabled_time_windows(
+                weekdays
+                    .iter()
+                    .map(|weekday| (*weekday, vec![lunch]))
+                    .collect(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[rstest(
+        expected, tested_datetime, //
+        case::before_opening(true, create_datetime(2024, 5, 15, 8, 29)),
+        case::at_opening(false, create_datetime(2024, 5, 15, 8, 30)),
+        case::lunch_break(true, create_datetime(2024, 5, 15, 12, 30)),
+        case::after_lunch_break(false, create_datetime(2024, 5, 15, 13, 0)),
+        case::at_closing(true, create_datetime(2024, 5, 15, 16, 30)),
+        case::saturday_morning(false, create_datetime(2024, 5, 18, 11, 59)),
+        case::saturday_noon(true, create_datetime(2024, 5, 18, 12, 0)),
+        case::sunday(true, create_datetime(2024, 5, 19, 10, 0)),
+    )]
+    fn is_datetime_forbidden_time_windows(expected: bool, tested_datetime: PrimitiveDateTime) {
+        assert_eq!(
+            expected,
+            create_business_hours().is_datetime_forbidden(&tested_datetime)
+        );
+    }
+
+    #[rstest(
+        expected, tested_day, //
+        case::weekday(false, create_datetime(2024, 5, 15, 0, 0)),
+        case::saturday(false, create_datetime(2024, 5, 18, 0, 0)),
+        case::sunday_without_window(true, create_datetime(2024, 5, 19, 0, 0)),
+    )]
+    fn is_day_forbidden_time_windows(expected: bool, tested_day: PrimitiveDateTime) {
+        assert_eq!(
+            expected,
+            create_business_hours().is_day_forbidden(&tested_day)
+        );
+    }
+
+    #[test]
+    fn is_day_forbidden_time_windows_after_min_datetime() {
+        let config = DateConstraintsBuilder::default()
+            .min_datetime(create_datetime(2024, 5, 15, 10, 0))
+            .max_datetime(create_datetime(2024, 5, 16, 9, 0))
+            .allowed_time_windows(
+                [(
+                    Weekday::Wednesday,
+                    vec![TimeWindow::new(time!(08:00), time!(12:00))],
+                )]
+                .into_iter()
+                .chain([(
+                    Weekday::Thursday,
+                    vec![TimeWindow::new(time!(10:00), Time::MIDNIGHT)],
+                )])
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        assert!(!config.is_day_forbidden(&create_datetime(2024, 5, 15, 0, 0)));
+        assert!(config.is_day_forbidden(&create_datetime(2024, 5, 16, 0, 0)));
+    }
+
+    #[test]
+    fn time_window_end_before_start() {
+        let config = DateConstraintsBuilder::default()
+            .disabled_time_windows(
+                [(
+                    Weekday::Monday,
+                    vec![TimeWindow::new(time!(13:00), time!(12:00))],
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+        assert_eq!(
+            config.unwrap_err(),
+            ConfigError::EmptyTimeWindow {
+                weekday: Weekday::Monday,
+                window: TimeWindow::new(time!(13:00), time!(12:00))
+            }
+        );
+    }
+
+    /// Constraints implementing only the required methods, forbidding the 13th of each month
+    struct NoThirteenth;
+
+    impl HasDateConstraints for NoThirteenth {
+        fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            date.day() == 13
+        }
+
+        fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            date.day() == 13
+        }
+    }
+
+    /// Constraints implementing only the required methods, forbidding the years before 2000
+    struct FromYear2000;
+
+    impl HasDateConstraints for FromYear2000 {
+        fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            date.year() < 2000
+        }
+
+        fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            date.year() < 2000
+        }
+    }
+
+    #[test]
+    fn default_methods_derived_from_days() {
+        let month = create_datetime(1999, 2, 1, 0, 0);
+        assert!(!NoThirteenth.is_month_forbidden(&month));
+        assert!(!NoThirteenth.is_year_forbidden(&month));
+        assert!(!NoThirteenth.is_year_group_forbidden(&month));
+
+        assert!(FromYear2000.is_month_forbidden(&month));
+        assert!(FromYear2000.is_year_forbidden(&month));
+        assert!(FromYear2000.is_year_group_forbidden(&month));
+        assert!(!FromYear2000.is_year_forbidden(&create_datetime(2000, 12, 31, 0, 0)));
+        assert!(!FromYear2000.is_year_group_forbidden(&create_datetime(2000, 1, 1, 0, 0)));
+    }
+
+    #[rstest(
+        expected, tested_date, //
+        case::enabled_date(false, create_datetime(2024, 5, 15, 10, 0)),
+        case::not_enabled_date(true, create_datetime(2024, 5, 16, 10, 0)),
+    )]
+    fn is_day_forbidden_enabled_unique_dates(expected: bool, tested_date: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .enabled_unique_dates(
+                [from_ymd(2024, 5, 15), from_ymd(2024, 6, 3)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_datetime_forbidden(&tested_date));
+        assert_eq!(expected, config.is_day_forbidden(&tested_date));
+    }
+
+    #[rstest(
+        expected, tested_date, //
+        case::enabled_weekday_in_range(false, create_datetime(2024, 6, 3, 0, 0)),
+        case::enabled_weekday_outside_range(true, create_datetime(2024, 5, 27, 0, 0)),
+        case::other_weekday_in_range(true, create_datetime(2024, 6, 4, 0, 0)),
+        case::enabled_weekday_in_second_range(false, create_datetime(2024, 8, 5, 0, 0)),
+    )]
+    fn is_day_forbidden_enabled_weekdays_and_ranges(
+        expected: bool,
+        tested_date: PrimitiveDateTime,
+    ) {
+        let config = DateConstraintsBuilder::default()
+            .enabled_weekdays([Weekday::Monday].into_iter().collect())
+            .enabled_date_ranges(vec![
+                from_ymd(2024, 6, 1)..=from_ymd(2024, 6, 30),
+                from_ymd(2024, 8, 1)..=from_ymd(2024, 8, 5),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_day_forbidden(&tested_date));
+    }
+
+    #[test]
+    fn enabled_dates_aggregation() {
+        let config = DateConstraintsBuilder::default()
+            .enabled_unique_dates([from_ymd(2024, 6, 3)].into_iter().collect())
+            .build()
+            .unwrap();
+        assert!(!config.is_month_forbidden(&create_datetime(2024, 6, 1, 0, 0)));
+        assert!(config.is_month_forbidden(&create_datetime(2024, 5, 1, 0, 0)));
+        assert!(!config.is_year_forbidden(&create_datetime(2024, 1, 1, 0, 0)));
+        assert!(config.is_year_forbidden(&create_datetime(2025, 1, 1, 0, 0)));
+        assert!(!config.is_year_group_forbidden(&create_datetime(2024, 1, 1, 0, 0)));
+        assert!(config.is_year_group_forbidden(&create_datetime(2040, 1, 1, 0, 0)));
+    }
+
+    #[test]
+    fn enabled_date_range_end_before_start() {
+        let config = DateConstraintsBuilder::default()
+            .enabled_date_ranges(vec![from_ymd(2024, 6, 30)..=from_ymd(2024, 6, 1)])
+            .build();
+        assert_eq!(
+            config.unwrap_err(),
+            ConfigError::EnabledRangeReversed {
+                start: from_ymd(2024, 6, 30),
+                end: from_ymd(2024, 6, 1)
+            }
+        );
+    }
+
+    #[rstest(
+        expected, tested_datetime, //
+        case::before(false, create_datetime(2024, 7, 14, 23, 59)),
+        case::inside(true, create_datetime(2024, 7, 20, 12, 0)),
+        case::end(true, create_datetime(2024, 8, 4, 0, 0)),
+        case::after(false, create_datetime(2024, 8, 4, 0, 1)),
+    )]
+    fn is_datetime_forbidden_disabled_ranges(expected: bool, tested_datetime: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .disabled_ranges(vec![
+                create_datetime(2024, 7, 15, 0, 0)..=create_datetime(2024, 8, 4, 0, 0),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_datetime_forbidden(&tested_datetime));
+    }
+
+    #[rstest(
+        expected, tested_day, //
+        case::starts_in_the_morning(false, create_datetime(2024, 7, 15, 0, 0)),
+        case::whole_day(true, create_datetime(2024, 7, 16, 0, 0)),
+        case::ends_in_the_evening(false, create_datetime(2024, 7, 31, 0, 0)),
+        case::ends_at_midnight(false, create_datetime(2024, 8, 1, 0, 0)),
+    )]
+    fn is_day_forbidden_disabled_ranges(expected: bool, tested_day: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .disabled_ranges(vec![
+                create_datetime(2024, 7, 15, 8, 0)..=create_datetime(2024, 7, 31, 18, 0),
+                create_datetime(2024, 7, 31, 20, 0)..=create_datetime(2024, 8, 1, 0, 0),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_day_forbidden(&tested_day));
+    }
+
+    #[test]
+    fn is_month_and_year_forbidden_disabled_ranges() {
+        let config = DateConstraintsBuilder::default()
+            .disabled_ranges(vec![
+                create_datetime(2024, 1, 1, 0, 0)..=create_datetime(2025, 2, 1, 0, 0),
+            ])
+            .build()
+            .unwrap();
+        assert!(config.is_month_forbidden(&create_datetime(2025, 1, 15, 0, 0)));
+        assert!(!config.is_month_forbidden(&create_datetime(2025, 2, 15, 0, 0)));
+        assert!(config.is_year_forbidden(&create_datetime(2024, 6, 1, 0, 0)));
+        assert!(!config.is_year_forbidden(&create_datetime(2025, 1, 1, 0, 0)));
+    }
+
+    #[test]
+    fn is_datetime_forbidden_many_disabled_ranges() {
+        let start = create_datetime(2000, 1, 1, 0, 0);
+        let config = DateConstraintsBuilder::default()
+            .disabled_ranges(
+                (0..10_000)
+                    .map(|day| {
+                        let day = start + Duration::days(day * 2);
+                        day..=day + Duration::hours(12)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .build()
+            .unwrap();
+        assert!(config.is_datetime_forbidden(&create_datetime(2010, 1, 2, 6, 0)));
+        assert!(!config.is_datetime_forbidden(&create_datetime(2010, 1, 1, 6, 0)));
+        assert!(!config.is_day_forbidden(&create_datetime(2010, 1, 2, 0, 0)));
+    }
+
+    #[rstest(
+        expected, tested_day, //
+        case::last_friday(true, create_datetime(2024, 5, 31, 0, 0)),
+        case::first_working_day(true, create_datetime(2024, 6, 3, 0, 0)),
+        case::other_day(false, create_datetime(2024, 6, 4, 0, 0)),
+    )]
+    fn is_day_forbidden_disabled_recurrences(expected: bool, tested_day: PrimitiveDateTime) {
+        let config = DateConstraintsBuilder::default()
+            .disabled_recurrences(vec![
+                "FREQ=MONTHLY;BYDAY=-1FR".parse().unwrap(),
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1"
+                    .parse()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(expected, config.is_day_forbidden(&tested_day));
+    }
+
+    #[test]
+    fn default_not_forbidden_at_edges() {
+        let config = DateConstraints::default();
+        let max = create_datetime(9999, 12, 31, 23, 59);
+        let min = create_datetime(-9999, 1, 1, 0, 0);
+        assert!(!config.is_day_forbidden(&max));
+        assert!(!config.is_week_forbidden(&create_datetime(9999, 12, 28, 0, 0)));
+        assert!(!config.is_month_forbidden(&max));
+        assert!(!config.is_year_forbidden(&max));
+        assert!(!config.is_year_group_forbidden(&max));
+        assert!(!config.is_day_forbidden(&min));
+        assert!(!config.is_year_group_forbidden(&min));
+    }
+
+    #[test]
+    fn disabled_recurrences_at_edges() {
+        let config = DateConstraintsBuilder::default()
+            .disabled_recurrences(vec!["FREQ=WEEKLY;BYDAY=MO,FR;BYSETPOS=1".parse().unwrap()])
+            .build()
+            .unwrap();
+        assert!(config.is_day_forbidden(&create_datetime(9999, 12, 27, 0, 0)));
+        assert!(!config.is_day_forbidden(&create_datetime(9999, 12, 31, 23, 59)));
+        assert!(config.is_day_forbidden(&create_datetime(-9999, 1, 1, 0, 0)));
+    }
 }
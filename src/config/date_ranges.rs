@@ -0,0 +1,185 @@
+use std::ops::RangeInclusive;
+
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "serde")]
+use crate::config::serde_formats::DateTimeRange;
+
+/// Index of inclusive ranges of datetimes, sorted and merged so that a datetime is looked up
+/// by a binary search. Empty ranges are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DateTimeRanges {
+    ranges: Vec<(PrimitiveDateTime, PrimitiveDateTime)>,
+}
+
+impl FromIterator<RangeInclusive<PrimitiveDateTime>> for DateTimeRanges {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<PrimitiveDateTime>>>(iter: I) -> Self {
+        let mut sorted: Vec<(PrimitiveDateTime, PrimitiveDateTime)> = iter
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .map(RangeInclusive::into_inner)
+            .collect();
+        sorted.sort_unstable();
+        let mut ranges: Vec<(PrimitiveDateTime, PrimitiveDateTime)> =
+            Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match ranges.last_mut() {
+                Some((_, last_end)) if start <= *last_end => *last_end = end.max(*last_end),
+                _ => ranges.push((start, end)),
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl From<Vec<RangeInclusive<PrimitiveDateTime>>> for DateTimeRanges {
+    fn from(ranges: Vec<RangeInclusive<PrimitiveDateTime>>) -> Self {
+        ranges.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateTimeRanges {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.ranges.iter().map(|(start, end)| DateTimeRange {
+            start: *start,
+            end: *end,
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DateTimeRanges {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ranges: Vec<DateTimeRange> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(ranges
+            .into_iter()
+            .map(|range| range.start..=range.end)
+            .collect())
+    }
+}
+
+impl DateTimeRanges {
+    /// Returns true if there is no range
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the sorted and merged ranges
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<PrimitiveDateTime>> + '_ {
+        self.ranges.iter().map(|(start, end)| *start..=*end)
+    }
+
+    /// Returns true if one of the ranges includes the given datetime
+    pub fn contains(&self, datetime: &PrimitiveDateTime) -> bool {
+        self.range_before(datetime)
+            .is_some_and(|(_, end)| datetime <= end)
+    }
+
+    /// Returns true if a single range includes every datetime from `start` to `end`
+    pub fn covers(&self, start: &PrimitiveDateTime, end: &PrimitiveDateTime) -> bool {
+        self.range_before(start)
+            .is_some_and(|(_, range_end)| start <= range_end && end <= range_end)
+    }
+
+    /// Returns the starts of the ranges from `start` to `end`
+    pub fn starts_between<'a>(
+        &'a self,
+        start: &'a PrimitiveDateTime,
+        end: &'a PrimitiveDateTime,
+    ) -> impl Iterator<Item = PrimitiveDateTime> + 'a {
+        let first = self
+            .ranges
+            .partition_point(|(range_start, _)| range_start < start);
+        self.ranges[first..]
+            .iter()
+            .map(|(range_start, _)| *range_start)
+            .take_while(move |range_start| range_start <= end)
+    }
+
+    /// Returns the ends of the ranges from `start` to `end`
+    pub fn ends_between<'a>(
+        &'a self,
+        start: &'a PrimitiveDateTime,
+        end: &'a PrimitiveDateTime,
+    ) -> impl Iterator<Item = PrimitiveDateTime> + 'a {
+        let first = self
+            .ranges
+            .partition_point(|(_, range_end)| range_end < start);
+        self.ranges[first..]
+            .iter()
+            .map(|(_, range_end)| *range_end)
+            .take_while(move |range_end| range_end <= end)
+    }
+
+    /// Returns the last range starting at or before the given datetime
+    fn range_before(
+        &self,
+        datetime: &PrimitiveDateTime,
+    ) -> Option<&(PrimitiveDateTime, PrimitiveDateTime)> {
+        let after = self.ranges.partition_point(|(start, _)| start <= datetime);
+        after.checked_sub(1).map(|index| &self.ranges[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::datetime;
+
+    fn create_ranges() -> DateTimeRanges {
+        vec![
+            datetime!(2024-05-10 00:00)..=datetime!(2024-05-12 12:00),
+            datetime!(2024-05-01 00:00)..=datetime!(2024-05-03 00:00),
+            datetime!(2024-05-11 00:00)..=datetime!(2024-05-20 00:00),
+            datetime!(2024-06-01 00:00)..=datetime!(2024-05-01 00:00),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn sorted_and_merged() {
+        assert_eq!(
+            vec![
+                datetime!(2024-05-01 00:00)..=datetime!(2024-05-03 00:00),
+                datetime!(2024-05-10 00:00)..=datetime!(2024-05-20 00:00),
+            ],
+            create_ranges().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest(
+        expected, datetime, //
+        case::before_first(false, datetime!(2024-04-30 23:59)),
+        case::first_start(true, datetime!(2024-05-01 00:00)),
+        case::first_end(true, datetime!(2024-05-03 00:00)),
+        case::between(false, datetime!(2024-05-03 00:01)),
+        case::merged(true, datetime!(2024-05-15 00:00)),
+        case::after_last(false, datetime!(2024-05-20 00:01)),
+    )]
+    fn contains(expected: bool, datetime: PrimitiveDateTime) {
+        assert_eq!(expected, create_ranges().contains(&datetime));
+    }
+
+    #[rstest(
+        expected, start, end, //
+        case::inside(true, datetime!(2024-05-11 00:00), datetime!(2024-05-19 00:00)),
+        case::across_gap(false, datetime!(2024-05-02 00:00), datetime!(2024-05-11 00:00)),
+        case::overflow(false, datetime!(2024-05-11 00:00), datetime!(2024-05-21 00:00)),
+    )]
+    fn covers(expected: bool, start: PrimitiveDateTime, end: PrimitiveDateTime) {
+        assert_eq!(expected, create_ranges().covers(&start, &end));
+    }
+
+    #[test]
+    fn ends_between() {
+        assert_eq!(
+            vec![datetime!(2024-05-20 00:00)],
+            create_ranges()
+                .ends_between(&datetime!(2024-05-04 00:00), &datetime!(2024-05-31 00:00))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use derive_builder::UninitializedFieldError;
+use time::{Date, PrimitiveDateTime, Weekday};
+
+use crate::{config::date_constraints::TimeWindow, dialog_view_type::DialogViewType};
+
+/// Error of the validation of a `PickerConfig`, of `DateConstraints` or of the options builders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// `min_datetime` is later than `max_datetime`
+    MinAfterMax {
+        min: PrimitiveDateTime,
+        max: PrimitiveDateTime,
+    },
+    /// `min_days` of `RangeOptions` is larger than its `max_days`
+    MinDaysAfterMaxDays { min_days: u32, max_days: u32 },
+    /// A range of `enabled_date_ranges` ends before its start
+    EnabledRangeReversed { start: Date, end: Date },
+    /// A time window of `allowed_time_windows` or `disabled_time_windows` includes no time
+    EmptyTimeWindow {
+        weekday: Weekday,
+        window: TimeWindow,
+    },
+    /// `initial_view_type` has a smaller scale than `selection_type`
+    InitialViewBeyondSelection {
+        initial_view_type: DialogViewType,
+        selection_type: DialogViewType,
+    },
+    /// `selection_type` is not reached by zooming in from `initial_view_type`,
+    /// e.g. days are not selected from the weeks view
+    UnreachableSelection {
+        initial_view_type: DialogViewType,
+        selection_type: DialogViewType,
+    },
+    /// `minute_step` is not a divisor of 60
+    InvalidMinuteStep { minute_step: u8 },
+    /// `initial_date` is forbidden by the date constraints
+    InitialDateForbidden { date: PrimitiveDateTime },
+    /// A number of columns of `KeyboardOptions` does not fit its grid,
+    /// it must be a divisor of `cells` if given, otherwise at least 1
+    InvalidKeyboardOption {
+        option: &'static str,
+        columns: u8,
+        cells: Option<u8>,
+    },
+    /// A required field of a builder was not set
+    UninitializedField(&'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MinAfterMax { .. } => {
+                write!(f, "min_datetime must be earlier or exactly at max_datetime")
+            }
+            ConfigError::MinDaysAfterMaxDays { .. } => {
+                write!(f, "min_days must be lower or equal to max_days")
+            }
+            ConfigError::EnabledRangeReversed { .. } => write!(
+                f,
+                "enabled_date_ranges must start earlier or exactly at their end"
+            ),
+            ConfigError::EmptyTimeWindow { .. } => {
+                write!(f, "time window start must be earlier than its end")
+            }
+            ConfigError::InitialViewBeyondSelection { .. } => {
+                write!(f, "initial_view_type can have at most selection_type scale")
+            }
+            ConfigError::UnreachableSelection {
+                initial_view_type,
+                selection_type,
+            } => write!(
+                f,
+                "selection_type {selection_type:?} can not be reached from initial_view_type {initial_view_type:?}"
+            ),
+            ConfigError::InvalidMinuteStep { minute_step } => {
+                write!(f, "The minute_step {minute_step} must be a divisor of 60.")
+            }
+            ConfigError::InitialDateForbidden { date } => write!(
+                f,
+                "The initial_date {date} is forbidden by the date_constraints."
+            ),
+            ConfigError::InvalidKeyboardOption {
+                option,
+                columns,
+                cells: Some(cells),
+            } => write!(f, "The {option} {columns} must be a divisor of {cells}."),
+            ConfigError::InvalidKeyboardOption {
+                option,
+                columns,
+                cells: None,
+            } => write!(f, "The {option} {columns} must be at least 1."),
+            ConfigError::UninitializedField(field) => {
+                write!(f, "`{field}` must be initialized")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<UninitializedFieldError> for ConfigError {
+    fn from(error: UninitializedFieldError) -> Self {
+        ConfigError::UninitializedField(error.field_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::{date, datetime, time};
+
+    #[rstest(
+        expected, error, //
+        case::min_after_max("min_datetime must be earlier or exactly at max_datetime", ConfigError::MinAfterMax { min: datetime!(2020-10-15 00:00), max: datetime!(2020-10-14 00:00) }),
+        case::min_days_after_max_days("min_days must be lower or equal to max_days", ConfigError::MinDaysAfterMaxDays { min_days: 3, max_days: 2 }),
+        case::enabled_range_reversed("enabled_date_ranges must start earlier or exactly at their end", ConfigError::EnabledRangeReversed { start: date!(2024 - 06 - 30), end: date!(2024 - 06 - 01) }),
+        case::empty_time_window("time window start must be earlier than its end", ConfigError::EmptyTimeWindow { weekday: Weekday::Monday, window: TimeWindow::new(time!(13:00), time!(12:00)) }),
+        case::initial_view_beyond_selection("initial_view_type can have at most selection_type scale", ConfigError::InitialViewBeyondSelection { initial_view_type: DialogViewType::Days, selection_type: DialogViewType::Months }),
+        case::unreachable_selection("selection_type Days can not be reached from initial_view_type Weeks", ConfigError::UnreachableSelection { initial_view_type: DialogViewType::Weeks, selection_type: DialogViewType::Days }),
+        case::invalid_minute_step("The minute_step 7 must be a divisor of 60.", ConfigError::InvalidMinuteStep { minute_step: 7 }),
+        case::invalid_keyboard_divisor("The month_columns 5 must be a divisor of 12.", ConfigError::InvalidKeyboardOption { option: "month_columns", columns: 5, cells: Some(12) }),
+        case::invalid_keyboard_zero("The minute_columns 0 must be at least 1.", ConfigError::InvalidKeyboardOption { option: "minute_columns", columns: 0, cells: None }),
+        case::initial_date_forbidden("The initial_date 2020-01-01 0:00:00.0 is forbidden by the date_constraints.", ConfigError::InitialDateForbidden { date: datetime!(2020-01-01 00:00) }),
+    )]
+    fn display(expected: &str, error: ConfigError) {
+        assert_eq!(expected, error.to_string());
+    }
+}
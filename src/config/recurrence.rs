@@ -0,0 +1,686 @@
+use std::{fmt, str::FromStr};
+
+use derive_builder::{Builder, UninitializedFieldError};
+use time::{Date, Month, Weekday};
+
+use crate::{utils::from_ymd, viewed_date::days_since};
+
+/// Frequency of a `Recurrence`, the `FREQ` part of an RRULE.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Frequency {
+    #[default]
+    Daily,
+    /// Weeks start on Monday
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Weekday of a `Recurrence`, optionally the n-th one of the month or year,
+/// counted from the end if negative, like `2TU` or `-1FR` in a `BYDAY` part.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RecurrenceDay {
+    pub nth: Option<i8>,
+    pub weekday: Weekday,
+}
+
+/// Error of the validation or of the parsing of a `Recurrence`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecurrenceParseError {
+    /// A part of the rule is not a `KEY=VALUE` pair
+    InvalidPart(String),
+    /// A part of the rule is not supported, like `COUNT`
+    UnsupportedPart(String),
+    /// The rule has no `FREQ` part
+    MissingFrequency,
+    /// The `FREQ` is not supported, like `HOURLY`
+    UnsupportedFrequency(String),
+    /// A number of the rule can not be parsed
+    InvalidNumber(String),
+    /// The `DTSTART` is not a `YYYYMMDD` date, or `start` is out of the years 0 to 9999
+    InvalidStart(String),
+    /// A month of `BYMONTH` is not from 1 to 12
+    InvalidMonth(u8),
+    /// A day of `BYDAY` is not an optional number followed by a weekday code
+    InvalidDay(String),
+    /// A weekday code is not `MO`, `TU`, `WE`, `TH`, `FR`, `SA` or `SU`
+    UnknownWeekday(String),
+    /// The n-th weekday of `BYDAY` is 0 or beyond the weekdays of the period,
+    /// from 1 to 5 in a month and from 1 to 53 in a year, or the same negative
+    InvalidNthDay(i8),
+    /// `interval` is 0
+    ZeroInterval,
+    /// `interval` is larger than 1 without a `start`
+    IntervalWithoutStart,
+    /// A weekly, monthly or yearly recurrence has neither a `start` nor a BY rule
+    MissingStartOrRule,
+    /// A day of `by_month_day` is not from 1 to 31 or from -31 to -1
+    InvalidMonthDay(i8),
+    /// A position of `by_set_pos` is 0
+    ZeroSetPos,
+    /// A required field of the builder was not set
+    UninitializedField(&'static str),
+}
+
+impl fmt::Display for RecurrenceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceParseError::InvalidPart(part) => write!(f, "invalid part {part}"),
+            RecurrenceParseError::UnsupportedPart(part) => write!(f, "unsupported part {part}"),
+            RecurrenceParseError::MissingFrequency => write!(f, "FREQ is required"),
+            RecurrenceParseError::UnsupportedFrequency(frequency) => {
+                write!(f, "unsupported frequency {frequency}")
+            }
+            RecurrenceParseError::InvalidNumber(number) => write!(f, "invalid number {number}"),
+            RecurrenceParseError::InvalidStart(start) => write!(f, "invalid start {start}"),
+            RecurrenceParseError::InvalidMonth(month) => write!(f, "invalid month {month}"),
+            RecurrenceParseError::InvalidDay(day) => write!(f, "invalid day {day}"),
+            RecurrenceParseError::UnknownWeekday(code) => write!(f, "unknown weekday {code}"),
+            RecurrenceParseError::InvalidNthDay(nth) => write!(
+                f,
+                "the n-th weekday {nth} must be from 1 to 5 in a month, or to 53 in a year, or the same negative"
+            ),
+            RecurrenceParseError::ZeroInterval => write!(f, "interval must be at least 1"),
+            RecurrenceParseError::IntervalWithoutStart => {
+                write!(f, "an interval larger than 1 requires a start")
+            }
+            RecurrenceParseError::MissingStartOrRule => write!(
+                f,
+                "a weekly, monthly or yearly recurrence requires a start or a BY rule"
+            ),
+            RecurrenceParseError::InvalidMonthDay(day) => write!(
+                f,
+                "by_month_day {day} must be from 1 to 31 or from -31 to -1"
+            ),
+            RecurrenceParseError::ZeroSetPos => write!(f, "by_set_pos can not be 0"),
+            RecurrenceParseError::UninitializedField(field) => {
+                write!(f, "`{field}` must be initialized")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceParseError {}
+
+impl From<UninitializedFieldError> for RecurrenceParseError {
+    fn from(error: UninitializedFieldError) -> Self {
+        RecurrenceParseError::UninitializedField(error.field_name())
+    }
+}
+
+/// Recurring dates described by a subset of the RFC 5545 RRULE,
+/// with the `FREQ`, `INTERVAL`, `BYMONTH`, `BYMONTHDAY`, `BYDAY` and `BYSETPOS` parts.
+/// A `DTSTART=YYYYMMDD` part can be given in the same string to anchor the `INTERVAL`.
+///
+/// Examples:
+/// - last Friday of every month: `FREQ=MONTHLY;BYDAY=-1FR`
+/// - every second Tuesday: `FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;DTSTART=20240102`
+/// - first working day of the month: `FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1`
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+#[builder(build_fn(validate = "Self::validate", error = "RecurrenceParseError"))]
+pub struct Recurrence {
+    /// Length of the periods of the recurrence
+    frequency: Frequency,
+
+    /// The recurrence happens every `interval` periods from the `start`
+    interval: u32,
+
+    /// First date of the recurrence, no date before is included, from the year 0 to 9999
+    start: Option<Date>,
+
+    /// Months including the recurring dates
+    by_month: Vec<Month>,
+
+    /// Days of the month, counted from the end if negative
+    by_month_day: Vec<i8>,
+
+    /// Weekdays, optionally the n-th ones of the month or year
+    by_day: Vec<RecurrenceDay>,
+
+    /// Positions of the dates in the set of the dates of each period, counted from the end if negative
+    by_set_pos: Vec<i16>,
+}
+
+impl Default for Recurrence {
+    fn default() -> Self {
+        Self {
+            frequency: Frequency::Daily,
+            interval: 1,
+            start: None,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_set_pos: Vec::new(),
+        }
+    }
+}
+
+impl RecurrenceBuilder {
+    fn validate(&self) -> Result<(), RecurrenceParseError> {
+        if self.interval == Some(0) {
+            return Err(RecurrenceParseError::ZeroInterval);
+        }
+        if self.interval.is_some_and(|interval| interval > 1) && self.start.is_none() {
+            return Err(RecurrenceParseError::IntervalWithoutStart);
+        }
+        let has_by_rule = [
+            self.by_month.as_ref().is_some_and(|v| !v.is_empty()),
+            self.by_month_day.as_ref().is_some_and(|v| !v.is_empty()),
+            self.by_day.as_ref().is_some_and(|v| !v.is_empty()),
+        ]
+        .contains(&true);
+        if !has_by_rule
+            && self.start.is_none()
+            && self.frequency.unwrap_or_default() != Frequency::Daily
+        {
+            return Err(RecurrenceParseError::MissingStartOrRule);
+        }
+        if let Some(day) = self
+            .by_month_day
+            .iter()
+            .flatten()
+            .find(|day| **day == 0 || day.abs() > 31)
+        {
+            return Err(RecurrenceParseError::InvalidMonthDay(*day));
+        }
+        // the n-th weekday is counted in the year only for a yearly recurrence without `BYMONTH`
+        let max_nth = if self.frequency == Some(Frequency::Yearly)
+            && self.by_month.as_ref().is_none_or(|v| v.is_empty())
+        {
+            53
+        } else {
+            5
+        };
+        if let Some(nth) = self
+            .by_day
+            .iter()
+            .flatten()
+            .filter_map(|day| day.nth)
+            .find(|nth| *nth == 0 || nth.unsigned_abs() > max_nth)
+        {
+            return Err(RecurrenceParseError::InvalidNthDay(nth));
+        }
+        if self.by_set_pos.iter().flatten().any(|pos| *pos == 0) {
+            return Err(RecurrenceParseError::ZeroSetPos);
+        }
+        // the start is written as a `YYYYMMDD` date by `Display`
+        if let Some(start) = self
+            .start
+            .flatten()
+            .filter(|start| !(0..=9999).contains(&start.year()))
+        {
+            return Err(RecurrenceParseError::InvalidStart(start.to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl Recurrence {
+    /// Returns true if the given date is one of the recurring dates
+    pub fn matches(&self, date: &Date) -> bool {
+        if self.start.is_some_and(|start| *date < start) || !self.is_in_interval(date) {
+            return false;
+        }
+        if self.by_set_pos.is_empty() {
+            return self.is_in_set(date);
+        }
+        let set: Vec<Date> = self
+            .period_dates(date)
+            .into_iter()
+            .filter(|day| self.is_in_set(day) && self.start.is_none_or(|start| *day >= start))
+            .collect();
+        self.by_set_pos.iter().any(|pos| {
+            let index = if *pos > 0 {
+                usize::try_from(pos - 1).ok()
+            } else {
+                set.len().checked_sub(pos.unsigned_abs().into())
+            };
+            index.and_then(|index| set.get(index)) == Some(date)
+        })
+    }
+
+    /// Returns true if the period of the given date is one of every `interval` periods from the `start`
+    fn is_in_interval(&self, date: &Date) -> bool {
+        let Some(start) = self.start else {
+            return true;
+        };
+        let periods = match self.frequency {
+            Frequency::Daily => (*date - start).whole_days(),
+            Frequency::Weekly => (week_start(date) - week_start(&start)).whole_weeks(),
+            Frequency::Monthly => {
+                i64::from(date.year() - start.year()) * 12 + i64::from(date.month() as u8)
+                    - i64::from(start.month() as u8)
+            }
+            Frequency::Yearly => i64::from(date.year() - start.year()),
+        };
+        periods % i64::from(self.interval) == 0
+    }
+
+    /// Returns true if the given date matches the BY rules, or the `start` without BY rules
+    fn is_in_set(&self, date: &Date) -> bool {
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+        if !self.by_month_day.is_empty() {
+            let length = date.month().length(date.year()) as i8;
+            let day = date.day() as i8;
+            if !self
+                .by_month_day
+                .iter()
+                .any(|d| *d == day || *d == day - length - 1)
+            {
+                return false;
+            }
+        }
+        if !self.by_day.is_empty() {
+            return self
+                .by_day
+                .iter()
+                .any(|by_day| self.is_by_day(by_day, date));
+        }
+        match (self.start, self.frequency) {
+            _ if !self.by_month_day.is_empty() => true,
+            (Some(start), Frequency::Weekly) => date.weekday() == start.weekday(),
+            (Some(start), Frequency::Monthly) => date.day() == start.day(),
+            (Some(start), Frequency::Yearly) => {
+                date.day() == start.day()
+                    && (!self.by_month.is_empty() || date.month() == start.month())
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns true if the given date is the weekday of the `BYDAY` entry,
+    /// the n-th weekday is counted in the year only for a yearly recurrence without `BYMONTH`
+    fn is_by_day(&self, by_day: &RecurrenceDay, date: &Date) -> bool {
+        if by_day.weekday != date.weekday() {
+            return false;
+        }
+        let Some(nth) = by_day.nth else {
+            return true;
+        };
+        let (day, length) = if self.frequency == Frequency::Yearly && self.by_month.is_empty() {
+            (date.ordinal(), time::util::days_in_year(date.year()))
+        } else {
+            (date.day().into(), date.month().length(date.year()).into())
+        };
+        let nth = i32::from(nth);
+        (nth > 0 && i32::from((day - 1) / 7 + 1) == nth)
+            || (nth < 0 && -i32::from((length - day) / 7 + 1) == nth)
+    }
+
+    /// Returns the dates of the period including the given date, for the `BYSETPOS` part
+    fn period_dates(&self, date: &Date) -> Vec<Date> {
+        let (first, last) = match self.frequency {
+            Frequency::Daily => (*date, *date),
+            Frequency::Weekly => {
+                // the weeks at the edges of the range of `Date` are truncated
+                let first = week_start(date);
+                let last = first
+                    .checked_add(time::Duration::days(6))
+                    .unwrap_or(Date::MAX);
+                (first, last)
+            }
+            Frequency::Monthly => {
+                let first = from_ymd(date.year(), date.month() as u8, 1);
+                (
+                    first,
+                    from_ymd(
+                        date.year(),
+                        date.month() as u8,
+                        date.month().length(date.year()),
+                    ),
+                )
+            }
+            Frequency::Yearly => (from_ymd(date.year(), 1, 1), from_ymd(date.year(), 12, 31)),
+        };
+        std::iter::successors(Some(first), |day| day.next_day())
+            .take_while(|day| *day <= last)
+            .collect()
+    }
+}
+
+/// Returns the Monday on or before the given date, `Date::MIN` if it is out of the range of `Date`
+fn week_start(date: &Date) -> Date {
+    date.checked_sub(time::Duration::days(
+        days_since(date.weekday(), Weekday::Monday).into(),
+    ))
+    .unwrap_or(Date::MIN)
+}
+
+fn parse_weekday(code: &str) -> Result<Weekday, RecurrenceParseError> {
+    match code {
+        "MO" => Ok(Weekday::Monday),
+        "TU" => Ok(Weekday::Tuesday),
+        "WE" => Ok(Weekday::Wednesday),
+        "TH" => Ok(Weekday::Thursday),
+        "FR" => Ok(Weekday::Friday),
+        "SA" => Ok(Weekday::Saturday),
+        "SU" => Ok(Weekday::Sunday),
+        _ => Err(RecurrenceParseError::UnknownWeekday(code.into())),
+    }
+}
+
+fn parse_list<T, F>(value: &str, parse: F) -> Result<Vec<T>, RecurrenceParseError>
+where
+    F: Fn(&str) -> Result<T, RecurrenceParseError>,
+{
+    value.split(',').map(|item| parse(item.trim())).collect()
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, RecurrenceParseError> {
+    value
+        .parse()
+        .map_err(|_| RecurrenceParseError::InvalidNumber(value.into()))
+}
+
+impl FromStr for Recurrence {
+    type Err = RecurrenceParseError;
+
+    /// Parses a recurrence like `FREQ=MONTHLY;BYDAY=-1FR`, an optional `RRULE:` prefix is ignored
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        let mut builder = RecurrenceBuilder::default();
+        let mut has_frequency = false;
+        for part in rule.split(';').filter(|part| !part.trim().is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| RecurrenceParseError::InvalidPart(part.into()))?;
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    has_frequency = true;
+                    builder.frequency(match value.trim().to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => {
+                            return Err(RecurrenceParseError::UnsupportedFrequency(value.into()));
+                        }
+                    });
+                }
+                "INTERVAL" => {
+                    builder.interval(parse_number(value.trim())?);
+                }
+                "DTSTART" => {
+                    let value = value.trim();
+                    let invalid_start = || RecurrenceParseError::InvalidStart(value.into());
+                    let number = |range: std::ops::Range<usize>| {
+                        value
+                            .get(range)
+                            .and_then(|number| number.parse::<i32>().ok())
+                            .ok_or_else(invalid_start)
+                    };
+                    if value.len() != 8 {
+                        return Err(invalid_start());
+                    }
+                    let month = u8::try_from(number(4..6)?)
+                        .ok()
+                        .and_then(|month| Month::try_from(month).ok())
+                        .ok_or_else(invalid_start)?;
+                    let day = u8::try_from(number(6..8)?).map_err(|_| invalid_start())?;
+                    let date = Date::from_calendar_date(number(0..4)?, month, day)
+                        .map_err(|_| invalid_start())?;
+                    builder.start(date);
+                }
+                "BYMONTH" => {
+                    builder.by_month(parse_list(value, |month| {
+                        let month = parse_number::<u8>(month)?;
+                        Month::try_from(month)
+                            .map_err(|_| RecurrenceParseError::InvalidMonth(month))
+                    })?);
+                }
+                "BYMONTHDAY" => {
+                    builder.by_month_day(parse_list(value, parse_number)?);
+                }
+                "BYSETPOS" => {
+                    builder.by_set_pos(parse_list(value, parse_number)?);
+                }
+                "BYDAY" => {
+                    builder.by_day(parse_list(value, |day| {
+                        let (nth, weekday) = day
+                            .len()
+                            .checked_sub(2)
+                            .and_then(|split| day.split_at_checked(split))
+                            .ok_or_else(|| RecurrenceParseError::InvalidDay(day.into()))?;
+                        Ok(RecurrenceDay {
+                            nth: match nth {
+                                "" => None,
+                                nth => Some(parse_number(nth.trim_start_matches('+'))?),
+                            },
+                            weekday: parse_weekday(&weekday.to_ascii_uppercase())?,
+                        })
+                    })?);
+                }
+                _ => return Err(RecurrenceParseError::UnsupportedPart(part.into())),
+            }
+        }
+        if !has_frequency {
+            return Err(RecurrenceParseError::MissingFrequency);
+        }
+        builder.build()
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+fn join<T, F: Fn(&T) -> String>(items: &[T], format: F) -> String {
+    items.iter().map(format).collect::<Vec<_>>().join(",")
+}
+
+impl fmt::Display for Recurrence {
+    /// Formats the recurrence as the RRULE it is parsed from, like `FREQ=MONTHLY;BYDAY=-1FR`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={frequency}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_month.is_empty() {
+            write!(
+                f,
+                ";BYMONTH={}",
+                join(&self.by_month, |m| (*m as u8).to_string())
+            )?;
+        }
+        if !self.by_month_day.is_empty() {
+            write!(f, ";BYMONTHDAY={}", join(&self.by_month_day, i8::to_string))?;
+        }
+        if !self.by_day.is_empty() {
+            let by_day = join(&self.by_day, |day| {
+                let nth = day.nth.map(|nth| nth.to_string()).unwrap_or_default();
+                format!("{nth}{}", weekday_code(day.weekday))
+            });
+            write!(f, ";BYDAY={by_day}")?;
+        }
+        if !self.by_set_pos.is_empty() {
+            write!(f, ";BYSETPOS={}", join(&self.by_set_pos, i16::to_string))?;
+        }
+        if let Some(start) = self.start {
+            write!(
+                f,
+                ";DTSTART={:04}{:02}{:02}",
+                start.year(),
+                start.month() as u8,
+                start.day()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Recurrence {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Recurrence {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rule = String::deserialize(deserializer)?;
+        rule.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::date;
+
+    #[rstest(
+        expected, rule, date, //
+        case::last_friday(true, "FREQ=MONTHLY;BYDAY=-1FR", date!(2024 - 05 - 31)),
+        case::not_last_friday(false, "FREQ=MONTHLY;BYDAY=-1FR", date!(2024 - 05 - 24)),
+        case::second_tuesday_of_month(true, "FREQ=MONTHLY;BYDAY=2TU", date!(2024 - 05 - 14)),
+        case::not_second_tuesday_of_month(false, "FREQ=MONTHLY;BYDAY=2TU", date!(2024 - 05 - 21)),
+        case::every_second_tuesday(true, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;DTSTART=20240102", date!(2024 - 05 - 07)),
+        case::every_second_tuesday_off_week(false, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;DTSTART=20240102", date!(2024 - 05 - 14)),
+        case::before_start(false, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;DTSTART=20240102", date!(2023 - 12 - 19)),
+        case::first_working_day(true, "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1", date!(2024 - 06 - 03)),
+        case::not_first_working_day(false, "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1", date!(2024 - 06 - 04)),
+        case::last_working_day(true, "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", date!(2024 - 08 - 30)),
+        case::last_day_of_month(true, "FREQ=MONTHLY;BYMONTHDAY=-1", date!(2024 - 02 - 29)),
+        case::yearly_month_day(true, "RRULE:FREQ=YEARLY;BYMONTH=4;BYMONTHDAY=13,14,15", date!(2025 - 04 - 14)),
+        case::yearly_other_month(false, "FREQ=YEARLY;BYMONTH=4;BYMONTHDAY=13,14,15", date!(2025 - 05 - 14)),
+        case::yearly_nth_in_year(true, "FREQ=YEARLY;BYDAY=1MO", date!(2024 - 01 - 01)),
+        case::yearly_nth_in_month(true, "FREQ=YEARLY;BYMONTH=5;BYDAY=1MO", date!(2024 - 05 - 06)),
+        case::yearly_last_nth_in_year(true, "FREQ=YEARLY;BYDAY=53MO", date!(2024 - 12 - 30)),
+        case::weekly_start_weekday(true, "FREQ=WEEKLY;DTSTART=20240102", date!(2024 - 05 - 14)),
+        case::monthly_start_day(true, "FREQ=MONTHLY;INTERVAL=3;DTSTART=20240115", date!(2024 - 07 - 15)),
+        case::monthly_start_day_off_month(false, "FREQ=MONTHLY;INTERVAL=3;DTSTART=20240115", date!(2024 - 06 - 15)),
+        case::monthly_by_month_start_day(true, "FREQ=MONTHLY;BYMONTH=3;DTSTART=20240315", date!(2025 - 03 - 15)),
+        case::monthly_by_month_other_day(false, "FREQ=MONTHLY;BYMONTH=3;DTSTART=20240315", date!(2025 - 03 - 16)),
+        case::monthly_by_month_first_day(false, "FREQ=MONTHLY;BYMONTH=3;DTSTART=20240315", date!(2025 - 03 - 01)),
+        case::monthly_by_month_other_month(false, "FREQ=MONTHLY;BYMONTH=3;DTSTART=20240315", date!(2025 - 04 - 15)),
+        case::daily(true, "FREQ=DAILY", date!(2024 - 06 - 15)),
+    )]
+    fn matches(expected: bool, rule: &str, date: Date) {
+        let recurrence: Recurrence = rule.parse().unwrap();
+        assert_eq!(expected, recurrence.matches(&date));
+    }
+
+    #[rstest(
+        expected, rule, date, //
+        case::weekly_first_at_max_week(true, "FREQ=WEEKLY;BYDAY=MO,FR;BYSETPOS=1", date!(9999 - 12 - 27)),
+        case::weekly_last_at_max(true, "FREQ=WEEKLY;BYDAY=MO,FR;BYSETPOS=-1", Date::MAX),
+        case::weekly_first_at_max(false, "FREQ=WEEKLY;BYDAY=MO,FR;BYSETPOS=1", Date::MAX),
+        case::weekly_first_at_min(true, "FREQ=WEEKLY;BYDAY=MO,FR;BYSETPOS=1", Date::MIN),
+        case::weekly_interval_at_max(true, "FREQ=WEEKLY;INTERVAL=2;BYDAY=FR;BYSETPOS=-1;DTSTART=99991201", Date::MAX),
+        case::monthly_last_at_max(true, "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", Date::MAX),
+        case::monthly_first_at_min(true, "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1", Date::MIN),
+        case::yearly_last_at_max(true, "FREQ=YEARLY;BYMONTHDAY=1,-1;BYSETPOS=-1", Date::MAX),
+        case::yearly_first_at_min(true, "FREQ=YEARLY;BYMONTHDAY=1,-1;BYSETPOS=1", Date::MIN),
+        case::yearly_first_at_max(false, "FREQ=YEARLY;BYMONTHDAY=1,-1;BYSETPOS=1", Date::MAX),
+    )]
+    fn matches_at_edges(expected: bool, rule: &str, date: Date) {
+        let recurrence: Recurrence = rule.parse().unwrap();
+        assert_eq!(expected, recurrence.matches(&date));
+    }
+
+    #[rstest(
+        expected, rule, //
+        case::missing_frequency(RecurrenceParseError::MissingFrequency, "BYDAY=MO"),
+        case::invalid_part(RecurrenceParseError::InvalidPart("BYDAY".into()), "FREQ=DAILY;BYDAY"),
+        case::unknown_frequency(RecurrenceParseError::UnsupportedFrequency("HOURLY".into()), "FREQ=HOURLY"),
+        case::unknown_part(RecurrenceParseError::UnsupportedPart("COUNT=3".into()), "FREQ=DAILY;COUNT=3"),
+        case::unknown_weekday(RecurrenceParseError::UnknownWeekday("XX".into()), "FREQ=WEEKLY;BYDAY=XX"),
+        case::non_ascii_weekday(RecurrenceParseError::InvalidDay("ก".into()), "FREQ=WEEKLY;BYDAY=ก"),
+        case::non_ascii_nth(RecurrenceParseError::InvalidNumber("ก".into()), "FREQ=MONTHLY;BYDAY=กMO"),
+        case::invalid_number(RecurrenceParseError::InvalidNumber("x".into()), "FREQ=MONTHLY;BYMONTHDAY=x"),
+        case::invalid_month(RecurrenceParseError::InvalidMonth(13), "FREQ=YEARLY;BYMONTH=13"),
+        case::zero_nth_day(RecurrenceParseError::InvalidNthDay(0), "FREQ=MONTHLY;BYDAY=0MO"),
+        case::nth_day_beyond_month(RecurrenceParseError::InvalidNthDay(6), "FREQ=MONTHLY;BYDAY=6MO"),
+        case::negative_nth_day_beyond_month(RecurrenceParseError::InvalidNthDay(-7), "FREQ=MONTHLY;BYDAY=-7FR"),
+        case::nth_day_beyond_month_of_year(RecurrenceParseError::InvalidNthDay(6), "FREQ=YEARLY;BYMONTH=5;BYDAY=6MO"),
+        case::nth_day_beyond_year(RecurrenceParseError::InvalidNthDay(54), "FREQ=YEARLY;BYDAY=54MO"),
+        case::interval_without_start(RecurrenceParseError::IntervalWithoutStart, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU"),
+        case::zero_interval(RecurrenceParseError::ZeroInterval, "FREQ=DAILY;INTERVAL=0"),
+        case::invalid_month_day(RecurrenceParseError::InvalidMonthDay(32), "FREQ=MONTHLY;BYMONTHDAY=32"),
+        case::zero_set_pos(RecurrenceParseError::ZeroSetPos, "FREQ=MONTHLY;BYDAY=MO;BYSETPOS=0"),
+        case::invalid_start(RecurrenceParseError::InvalidStart("20241301".into()), "FREQ=DAILY;DTSTART=20241301"),
+        case::start_before_year_0(RecurrenceParseError::InvalidStart("-0100-03-15".into()), "FREQ=DAILY;DTSTART=-1000315"),
+        case::weekly_without_rule(RecurrenceParseError::MissingStartOrRule, "FREQ=WEEKLY"),
+    )]
+    fn parse_error(expected: RecurrenceParseError, rule: &str) {
+        assert_eq!(Err(expected), rule.parse::<Recurrence>());
+    }
+
+    #[test]
+    fn parse_error_display() {
+        assert_eq!(
+            "unsupported part COUNT=3",
+            RecurrenceParseError::UnsupportedPart("COUNT=3".into()).to_string()
+        );
+        assert_eq!(
+            "FREQ is required",
+            RecurrenceParseError::MissingFrequency.to_string()
+        );
+    }
+
+    #[test]
+    fn builder() {
+        let recurrence = RecurrenceBuilder::default()
+            .frequency(Frequency::Monthly)
+            .by_day(vec![RecurrenceDay {
+                nth: Some(-1),
+                weekday: Weekday::Friday,
+            }])
+            .build()
+            .unwrap();
+        assert_eq!(
+            recurrence,
+            "FREQ=MONTHLY;BYDAY=-1FR".parse::<Recurrence>().unwrap()
+        );
+    }
+
+    #[rstest(
+        start, //
+        case::before_year_0(date!(-0001 - 03 - 15)),
+    )]
+    fn builder_start_out_of_display(start: Date) {
+        let result = RecurrenceBuilder::default()
+            .frequency(Frequency::Daily)
+            .start(start)
+            .build();
+        assert_eq!(
+            Err(RecurrenceParseError::InvalidStart(start.to_string())),
+            result
+        );
+    }
+
+    #[rstest(
+        rule, //
+        case::by_day("FREQ=MONTHLY;BYDAY=-1FR"),
+        case::interval("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;DTSTART=20240102"),
+        case::set_pos("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1"),
+        case::month_days("FREQ=YEARLY;BYMONTH=4;BYMONTHDAY=13,14,15"),
+        case::start_at_year_0("FREQ=DAILY;DTSTART=00000315"),
+        case::start_at_year_9999("FREQ=YEARLY;DTSTART=99991231"),
+    )]
+    fn display(rule: &str) {
+        assert_eq!(rule, rule.parse::<Recurrence>().unwrap().to_string());
+    }
+}
@@ -0,0 +1,307 @@
+//! Formats of the `serde` feature, the JSON schema of the configurations is:
+//! - dates are ISO 8601 strings like `"2024-05-31"`
+//! - datetimes are ISO 8601 strings without offset like `"2024-05-31T10:00:00"`,
+//!   the fraction of second is optional like `"2024-05-31T10:00:00.5"`
+//! - times are ISO 8601 strings like `"10:00:00"` or `"10:00:00.5"`
+//! - weekdays and months are English names like `"Monday"` and `"January"`
+//! - recurrences are RRULE strings like `"FREQ=MONTHLY;BYDAY=-1FR"`
+//! - view types are names like `"Months"`
+//! - calendar eras are `"Christian"` or objects like `{ "Buddhist": { "april_new_year": false } }`
+//! - week numberings are `"Iso"` or `"Us"`
+//! - fiscal calendars are objects like `{ "start_month": "October" }`
+//! - datetime ranges are objects like `{ "start": "2024-05-31T10:00:00", "end": "2024-05-31T12:00:00" }`
+//! - date ranges of `enabled_date_ranges` are inclusive objects like `{ "start": "2024-05-01", "end": "2024-05-31" }`
+//! - UTC offsets are strings with hours, minutes and seconds like `"+07:00:00"`
+//! - sets are arrays in ascending order, weekdays from Monday to Sunday, and the time windows
+//!   are objects keyed by weekday from Monday to Sunday, so the output is the same for the same configuration
+//! - missing fields take their default value, and the builders validation is run
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::Error as _};
+use time::{
+    PrimitiveDateTime, Time, Weekday, format_description::BorrowedFormatItem,
+    macros::format_description,
+};
+
+const DATETIME_FORMAT: &[BorrowedFormatItem] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]");
+
+const WHOLE_SECONDS_DATETIME_FORMAT: &[BorrowedFormatItem] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+
+const TIME_FORMAT: &[BorrowedFormatItem] =
+    format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]");
+
+const WHOLE_SECONDS_TIME_FORMAT: &[BorrowedFormatItem] =
+    format_description!("[hour]:[minute]:[second]");
+
+/// Datetimes like `"2024-05-31T10:00:00"`, the fraction of second is written only if not zero
+pub(crate) mod iso_datetime {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        datetime: &PrimitiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let format = if datetime.nanosecond() == 0 {
+            WHOLE_SECONDS_DATETIME_FORMAT
+        } else {
+            DATETIME_FORMAT
+        };
+        serializer.serialize_str(&datetime.format(format).map_err(S::Error::custom)?)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PrimitiveDateTime, D::Error> {
+        let datetime = String::deserialize(deserializer)?;
+        PrimitiveDateTime::parse(&datetime, DATETIME_FORMAT).map_err(D::Error::custom)
+    }
+
+    /// Optional datetimes, `None` is `null`
+    pub(crate) mod option {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer>(
+            datetime: &Option<PrimitiveDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match datetime {
+                Some(datetime) => super::serialize(datetime, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<PrimitiveDateTime>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|datetime| {
+                    PrimitiveDateTime::parse(&datetime, DATETIME_FORMAT).map_err(D::Error::custom)
+                })
+                .transpose()
+        }
+    }
+}
+
+pub(crate) use iso_datetime::option as option_iso_datetime;
+
+/// Serializes a time like `"10:00:00"`, the fraction of second is written only if not zero
+pub(crate) fn serialize_time<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+    let format = if time.nanosecond() == 0 {
+        WHOLE_SECONDS_TIME_FORMAT
+    } else {
+        TIME_FORMAT
+    };
+    serializer.serialize_str(&time.format(format).map_err(S::Error::custom)?)
+}
+
+/// Deserializes a time like `"10:00:00"` or `"10:00:00.5"`
+pub(crate) fn deserialize_time<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Time, D::Error> {
+    let time = String::deserialize(deserializer)?;
+    Time::parse(&time, TIME_FORMAT).map_err(D::Error::custom)
+}
+
+/// Deserializes an optional datetime of a builder field with a stripped option setter,
+/// a `null` datetime is left unset like a missing one
+pub(crate) fn builder_iso_datetime<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Option<PrimitiveDateTime>>, D::Error> {
+    option_iso_datetime::deserialize(deserializer).map(|datetime| datetime.map(Some))
+}
+
+/// Serializes a set in ascending order
+pub(crate) fn sorted_set<S: Serializer, T: Serialize + Ord>(
+    set: &HashSet<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(set.iter().collect::<BTreeSet<_>>())
+}
+
+/// Serializes a set of weekdays from Monday to Sunday
+pub(crate) fn sorted_weekdays<S: Serializer>(
+    weekdays: &HashSet<Weekday>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut weekdays: Vec<&Weekday> = weekdays.iter().collect();
+    weekdays.sort_by_key(|weekday| weekday.number_days_from_monday());
+    serializer.collect_seq(weekdays)
+}
+
+/// Serializes a map keyed by weekday from Monday to Sunday
+pub(crate) fn sorted_weekday_map<S: Serializer, T: Serialize>(
+    map: &HashMap<Weekday, T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries: Vec<(&Weekday, &T)> = map.iter().collect();
+    entries.sort_by_key(|(weekday, _)| weekday.number_days_from_monday());
+    serializer.collect_map(entries)
+}
+
+/// Inclusive range of datetimes
+#[derive(Serialize, Deserialize)]
+pub(crate) struct DateTimeRange {
+    #[serde(with = "iso_datetime")]
+    pub start: PrimitiveDateTime,
+    #[serde(with = "iso_datetime")]
+    pub end: PrimitiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{
+        PickerConfig,
+        date_constraints::{DateConstraints, HasDateConstraints},
+    };
+    use crate::dialog_view_type::DialogViewType;
+
+    use time::macros::datetime;
+
+    const DATE_CONSTRAINTS: &str = r#"{
+        "min_datetime": "2024-01-01T08:00:00",
+        "disabled_weekdays": ["Sunday"],
+        "disabled_months": ["April"],
+        "disabled_unique_dates": ["2024-05-31"],
+        "disabled_recurrences": ["FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1"],
+        "disabled_ranges": [{ "start": "2024-06-10T00:00:00", "end": "2024-06-12T23:59:59" }],
+        "allowed_time_windows": {
+            "Monday": [{ "start": "09:00:00", "end": "17:00:00" }]
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_date_constraints() {
+        let config: DateConstraints = serde_json::from_str(DATE_CONSTRAINTS).unwrap();
+        assert!(config.is_datetime_forbidden(&datetime!(2023-12-31 10:00)));
+        assert!(config.is_day_forbidden(&datetime!(2024-05-05 00:00)));
+        assert!(config.is_month_forbidden(&datetime!(2024-04-01 00:00)));
+        assert!(config.is_day_forbidden(&datetime!(2024-05-31 00:00)));
+        assert!(config.is_day_forbidden(&datetime!(2024-07-01 00:00)));
+        assert!(config.is_day_forbidden(&datetime!(2024-06-11 00:00)));
+        assert!(config.is_datetime_forbidden(&datetime!(2024-06-17 08:00)));
+        assert!(!config.is_datetime_forbidden(&datetime!(2024-06-17 09:00)));
+    }
+
+    #[test]
+    fn serialize_date_constraints_round_trip() {
+        let config: DateConstraints = serde_json::from_str(DATE_CONSTRAINTS).unwrap();
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["min_datetime"], "2024-01-01T08:00:00");
+        assert_eq!(json["disabled_unique_dates"][0], "2024-05-31");
+        assert_eq!(json["disabled_weekdays"][0], "Sunday");
+        let round_trip: DateConstraints = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(json, serde_json::to_value(&round_trip).unwrap());
+    }
+
+    #[test]
+    fn serialize_sorted_sets_and_ranges() {
+        let json = r#"{
+            "disabled_weekdays": ["Sunday", "Wednesday", "Monday", "Saturday"],
+            "disabled_years": [2030, 2024, 2027],
+            "disabled_unique_dates": ["2024-12-31", "2024-01-01", "2024-05-31"],
+            "enabled_date_ranges": [{ "start": "2024-05-01", "end": "2024-05-31" }],
+            "disabled_time_windows": {
+                "Sunday": [{ "start": "12:00:00", "end": "13:00:00" }],
+                "Tuesday": [{ "start": "12:00:00", "end": "13:00:00" }],
+                "Monday": [{ "start": "12:00:00", "end": "13:00:00" }]
+            }
+        }"#;
+        let config: DateConstraints = serde_json::from_str(json).unwrap();
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            r#"["Monday","Wednesday","Saturday","Sunday"]"#,
+            value["disabled_weekdays"].to_string()
+        );
+        assert_eq!("[2024,2027,2030]", value["disabled_years"].to_string());
+        assert_eq!(
+            r#"["2024-01-01","2024-05-31","2024-12-31"]"#,
+            value["disabled_unique_dates"].to_string()
+        );
+        let text = serde_json::to_string(&config).unwrap();
+        assert!(
+            text.contains(r#""enabled_date_ranges":[{"start":"2024-05-01","end":"2024-05-31"}]"#)
+        );
+        let monday = text.find(r#""Monday":[{"start""#).unwrap();
+        let tuesday = text.find(r#""Tuesday":[{"start""#).unwrap();
+        let sunday = text.find(r#""Sunday":[{"start""#).unwrap();
+        assert!(monday < tuesday && tuesday < sunday);
+        let round_trip: DateConstraints = serde_json::from_str(&text).unwrap();
+        assert_eq!(text, serde_json::to_string(&round_trip).unwrap());
+    }
+
+    #[test]
+    fn serialize_utc_offset() {
+        let config: PickerConfig<DateConstraints> =
+            serde_json::from_str(r#"{ "utc_offset": "-05:30:00" }"#).unwrap();
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!("-05:30:00", value["utc_offset"]);
+        assert_eq!(
+            serde_json::Value::Null,
+            serde_json::to_value(PickerConfig::<DateConstraints>::default()).unwrap()["utc_offset"]
+        );
+    }
+
+    #[test]
+    fn subseconds_round_trip() {
+        let json = r#"{
+            "min_datetime": "2024-01-01T08:00:00.5",
+            "allowed_time_windows": {
+                "Monday": [{ "start": "09:00:00.25", "end": "17:00:00" }]
+            }
+        }"#;
+        let config: DateConstraints = serde_json::from_str(json).unwrap();
+        assert_eq!(config.min_bound(), Some(datetime!(2024-01-01 08:00:00.5)));
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value["min_datetime"], "2024-01-01T08:00:00.5");
+        assert_eq!(
+            value["allowed_time_windows"]["Monday"][0]["start"],
+            "09:00:00.25"
+        );
+        assert_eq!(
+            value["allowed_time_windows"]["Monday"][0]["end"],
+            "17:00:00"
+        );
+        let round_trip: DateConstraints = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.min_bound(), config.min_bound());
+    }
+
+    #[test]
+    fn deserialize_date_constraints_validated() {
+        let result = serde_json::from_str::<DateConstraints>(
+            r#"{ "min_datetime": "2024-02-01T00:00:00", "max_datetime": "2024-01-01T00:00:00" }"#,
+        );
+        assert!(result.unwrap_err().to_string().contains("min_datetime"));
+    }
+
+    #[test]
+    fn picker_config_round_trip() {
+        let json = r#"{
+            "date_constraints": { "disabled_weekdays": ["Saturday", "Sunday"] },
+            "initial_date": "2024-05-31T10:00:00",
+            "selection_type": "Months",
+            "initial_view_type": "Years",
+            "calendar_era": { "Buddhist": { "april_new_year": false } },
+            "fiscal_calendar": { "start_month": "October" },
+            "first_weekday": "Monday"
+        }"#;
+        let config: PickerConfig<DateConstraints> = serde_json::from_str(json).unwrap();
+        assert_eq!(config.selection_type(), &DialogViewType::Months);
+        assert_eq!(config.minute_step(), &5);
+        assert!(config.is_day_forbidden(&datetime!(2024-06-01 00:00)));
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value["initial_date"], "2024-05-31T10:00:00");
+        let round_trip: PickerConfig<DateConstraints> = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.fiscal_calendar(), config.fiscal_calendar());
+    }
+
+    #[test]
+    fn deserialize_picker_config_validated() {
+        let result = serde_json::from_str::<PickerConfig<DateConstraints>>(
+            r#"{ "initial_view_type": "Days", "selection_type": "Years" }"#,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,519 @@
+use std::fmt;
+
+use derive_builder::{Builder, UninitializedFieldError};
+use derive_getters::Getters;
+use time::{Date, Month, PrimitiveDateTime, Time, Weekday, error::ComponentRange};
+
+use crate::{
+    calendar_era::CalendarEra,
+    locale::{Locale, digit_value, to_thai_digits},
+    viewed_date::YearNumber,
+};
+
+/// Fields of a `DateFormat` pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    Day,
+    DayPadded,
+    MonthPadded,
+    MonthName,
+    MonthAbbreviation,
+    WeekdayName,
+    WeekdayAbbreviation,
+    Year,
+    Era,
+    HourPadded,
+    MinutePadded,
+}
+
+/// Error of the validation of a `DateFormat` pattern or of the parsing of a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateFormatError {
+    /// The pattern contains an unknown field like `%Q`
+    UnknownField(char),
+    /// The pattern ends with a single `%`
+    SinglePercent,
+    /// The pattern has no day, month or year field, it can not be parsed
+    MissingDateField,
+    /// The text does not contain the expected field, like a "day" or a "month name", at the given rest of the text
+    ExpectedField { field: &'static str, rest: String },
+    /// The text does not contain the literal character of the pattern at the given rest of the text
+    ExpectedLiteral { literal: char, rest: String },
+    /// The month number is not from 1 to 12
+    InvalidMonth(i32),
+    /// The text continues after the end of the pattern
+    TrailingText(String),
+    /// The day, month and year do not form a date, or the hour and minute a time
+    InvalidDateTime(ComponentRange),
+    /// The day and month do not exist in the year of the `calendar_era`
+    NotInYear {
+        day: u8,
+        month: Month,
+        year: YearNumber,
+    },
+    /// The weekday of the text is not the weekday of the date
+    WrongWeekday { date: Date, weekday: Weekday },
+    /// A required field of the builder was not set
+    UninitializedField(&'static str),
+}
+
+impl fmt::Display for DateFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateFormatError::UnknownField(field) => {
+                write!(f, "unknown field %{field} in the pattern")
+            }
+            DateFormatError::SinglePercent => write!(f, "the pattern must not end with a single %"),
+            DateFormatError::MissingDateField => {
+                write!(f, "the pattern must contain a day, a month and a year")
+            }
+            DateFormatError::ExpectedField { field, rest } => {
+                write!(f, "expected {field} at \"{rest}\"")
+            }
+            DateFormatError::ExpectedLiteral { literal, rest } => {
+                write!(f, "expected \"{literal}\" at \"{rest}\"")
+            }
+            DateFormatError::InvalidMonth(month) => write!(f, "invalid month {month}"),
+            DateFormatError::TrailingText(rest) => write!(f, "unexpected trailing text \"{rest}\""),
+            DateFormatError::InvalidDateTime(error) => write!(f, "{error}"),
+            DateFormatError::NotInYear { day, month, year } => {
+                write!(f, "{day} {month} does not exist in the year {year}")
+            }
+            DateFormatError::WrongWeekday { date, weekday } => {
+                write!(f, "{date} is not a {weekday}")
+            }
+            DateFormatError::UninitializedField(field) => {
+                write!(f, "`{field}` must be initialized")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateFormatError {}
+
+impl From<UninitializedFieldError> for DateFormatError {
+    fn from(error: UninitializedFieldError) -> Self {
+        DateFormatError::UninitializedField(error.field_name())
+    }
+}
+
+impl From<ComponentRange> for DateFormatError {
+    fn from(error: ComponentRange) -> Self {
+        DateFormatError::InvalidDateTime(error)
+    }
+}
+
+/// Formatting and parsing of datetimes as localized text.
+///
+/// The pattern is a text in which the following fields are replaced:
+/// - `%e` day of the month, `%d` day of the month padded to 2 digits
+/// - `%m` month padded to 2 digits, `%B` full month name, `%b` abbreviated month name
+/// - `%A` full weekday name, `%a` abbreviated weekday name
+/// - `%Y` year of the `calendar_era`, `%E` abbreviated name of the `calendar_era`
+/// - `%H` hour padded to 2 digits, `%M` minute padded to 2 digits
+/// - `%%` a literal `%`
+#[derive(Clone, Debug, Builder, Getters)]
+#[builder(default)]
+#[builder(build_fn(validate = "Self::validate", error = "DateFormatError"))]
+pub struct DateFormat {
+    /// Pattern of the text, like "%e %B %Y" for "1 มกราคม 2567"
+    #[builder(setter(into))]
+    pattern: String,
+
+    /// Language of the month, weekday and era names
+    locale: Locale,
+
+    /// Era of the years
+    calendar_era: CalendarEra,
+
+    /// If true, the numbers are written with Thai digits `๐` to `๙`
+    thai_digits: bool,
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self {
+            pattern: "%e %B %Y".into(),
+            locale: Locale::Thai,
+            calendar_era: CalendarEra::Buddhist {
+                april_new_year: false,
+            },
+            thai_digits: false,
+        }
+    }
+}
+
+impl DateFormatBuilder {
+    fn validate(&self) -> Result<(), DateFormatError> {
+        if let Some(pattern) = &self.pattern {
+            tokens(pattern)?;
+        }
+        Ok(())
+    }
+}
+
+impl DateFormat {
+    /// Returns the given datetime as text
+    pub fn format(&self, datetime: &PrimitiveDateTime) -> String {
+        let date = datetime.date();
+        let mut text = String::new();
+        for token in tokens(&self.pattern).expect("pattern is validated by the builder") {
+            match token {
+                Token::Literal(c) => text.push(c),
+                Token::Day => text.push_str(&self.number(date.day().into(), 1)),
+                Token::DayPadded => text.push_str(&self.number(date.day().into(), 2)),
+                Token::MonthPadded => text.push_str(&self.number(date.month() as i32, 2)),
+                Token::MonthName => text.push_str(self.locale.month_name(date.month())),
+                Token::MonthAbbreviation => {
+                    text.push_str(self.locale.month_abbreviation(date.month()))
+                }
+                Token::WeekdayName => text.push_str(self.locale.weekday_name(date.weekday())),
+                Token::WeekdayAbbreviation => {
+                    text.push_str(self.locale.weekday_abbreviation(date.weekday()))
+                }
+                Token::Year => text.push_str(&self.number(self.calendar_era.year(&date), 1)),
+                Token::Era => text.push_str(self.locale.era_abbreviation(&self.calendar_era)),
+                Token::HourPadded => text.push_str(&self.number(datetime.hour().into(), 2)),
+                Token::MinutePadded => text.push_str(&self.number(datetime.minute().into(), 2)),
+            }
+        }
+        text
+    }
+
+    /// Returns the datetime written in the given text,
+    /// numbers are accepted with both ASCII and Thai digits
+    pub fn parse(&self, text: &str) -> Result<PrimitiveDateTime, DateFormatError> {
+        let mut rest = text;
+        let mut day = None;
+        let mut month = None;
+        let mut weekday = None;
+        let mut year = None;
+        let mut hour = 0;
+        let mut minute = 0;
+        for token in tokens(&self.pattern)? {
+            rest = match token {
+                Token::Literal(c) if c.is_whitespace() => {
+                    let trimmed = rest.trim_start();
+                    if trimmed.len() == rest.len() {
+                        return Err(unexpected("whitespace", rest));
+                    }
+                    trimmed
+                }
+                Token::Literal(c) => {
+                    rest.strip_prefix(c)
+                        .ok_or_else(|| DateFormatError::ExpectedLiteral {
+                            literal: c,
+                            rest: rest.into(),
+                        })?
+                }
+                Token::Day | Token::DayPadded => {
+                    let (value, rest) =
+                        parse_number(rest, 2).ok_or_else(|| unexpected("day", rest))?;
+                    day = Some(value);
+                    rest
+                }
+                Token::MonthPadded => {
+                    let (value, rest) =
+                        parse_number(rest, 2).ok_or_else(|| unexpected("month", rest))?;
+                    month = Some(
+                        u8::try_from(value)
+                            .ok()
+                            .and_then(|m| Month::try_from(m).ok())
+                            .ok_or(DateFormatError::InvalidMonth(value))?,
+                    );
+                    rest
+                }
+                Token::MonthName | Token::MonthAbbreviation => {
+                    let (value, rest) = parse_name(
+                        rest,
+                        months().map(|m| {
+                            let name = if token == Token::MonthName {
+                                self.locale.month_name(m)
+                            } else {
+                                self.locale.month_abbreviation(m)
+                            };
+                            (name, m)
+                        }),
+                    )
+                    .ok_or_else(|| unexpected("month name", rest))?;
+                    month = Some(value);
+                    rest
+                }
+                Token::WeekdayName | Token::WeekdayAbbreviation => {
+                    let (value, rest) = parse_name(
+                        rest,
+                        weekdays().map(|w| {
+                            let name = if token == Token::WeekdayName {
+                                self.locale.weekday_name(w)
+                            } else {
+                                self.locale.weekday_abbreviation(w)
+                            };
+                            (name, w)
+                        }),
+                    )
+                    .ok_or_else(|| unexpected("weekday name", rest))?;
+                    weekday = Some(value);
+                    rest
+                }
+                Token::Year => {
+                    let (sign, unsigned) = match rest.strip_prefix('-') {
+                        Some(unsigned) => (-1, unsigned),
+                        None => (1, rest),
+                    };
+                    let (value, rest) =
+                        parse_number(unsigned, 6).ok_or_else(|| unexpected("year", rest))?;
+                    year = Some(sign * value);
+                    rest
+                }
+                Token::Era => rest
+                    .strip_prefix(self.locale.era_abbreviation(&self.calendar_era))
+                    .ok_or_else(|| unexpected("era", rest))?,
+                Token::HourPadded => {
+                    let (value, rest) =
+                        parse_number(rest, 2).ok_or_else(|| unexpected("hour", rest))?;
+                    hour = value;
+                    rest
+                }
+                Token::MinutePadded => {
+                    let (value, rest) =
+                        parse_number(rest, 2).ok_or_else(|| unexpected("minute", rest))?;
+                    minute = value;
+                    rest
+                }
+            };
+        }
+        if !rest.is_empty() {
+            return Err(DateFormatError::TrailingText(rest.into()));
+        }
+
+        let (Some(day), Some(month), Some(year)) = (day, month, year) else {
+            return Err(DateFormatError::MissingDateField);
+        };
+        let date = Date::from_calendar_date(self.gregorian_year(year, month), month, day as u8)?;
+        if self.calendar_era.year(&date) != year {
+            return Err(DateFormatError::NotInYear {
+                day: day as u8,
+                month,
+                year,
+            });
+        }
+        if let Some(weekday) = weekday
+            && weekday != date.weekday()
+        {
+            return Err(DateFormatError::WrongWeekday { date, weekday });
+        }
+        let time = Time::from_hms(hour as u8, minute as u8, 0)?;
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+
+    /// Returns the given number as text, padded with zeros to the given width
+    fn number(&self, value: i32, width: usize) -> String {
+        let text = format!("{value:0width$}");
+        if self.thai_digits {
+            to_thai_digits(&text)
+        } else {
+            text
+        }
+    }
+
+    /// Returns the Gregorian year of the given year of the `calendar_era` in the given month
+    fn gregorian_year(&self, year: YearNumber, month: Month) -> YearNumber {
+        let gregorian_year = self.calendar_era.to_gregorian_year(year);
+        let date = Date::from_calendar_date(gregorian_year, month, 1);
+        match date {
+            Ok(date) if self.calendar_era.year(&date) < year => gregorian_year + 1,
+            _ => gregorian_year,
+        }
+    }
+}
+
+/// Returns the fields of the given pattern
+fn tokens(pattern: &str) -> Result<Vec<Token>, DateFormatError> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            tokens.push(Token::Literal(c));
+            continue;
+        }
+        tokens.push(match chars.next() {
+            Some('%') => Token::Literal('%'),
+            Some('e') => Token::Day,
+            Some('d') => Token::DayPadded,
+            Some('m') => Token::MonthPadded,
+            Some('B') => Token::MonthName,
+            Some('b') => Token::MonthAbbreviation,
+            Some('A') => Token::WeekdayName,
+            Some('a') => Token::WeekdayAbbreviation,
+            Some('Y') => Token::Year,
+            Some('E') => Token::Era,
+            Some('H') => Token::HourPadded,
+            Some('M') => Token::MinutePadded,
+            Some(other) => return Err(DateFormatError::UnknownField(other)),
+            None => return Err(DateFormatError::SinglePercent),
+        });
+    }
+    Ok(tokens)
+}
+
+/// Returns the number at the start of the given text with at most `max_digits` digits,
+/// and the rest of the text
+fn parse_number(text: &str, max_digits: usize) -> Option<(i32, &str)> {
+    let mut value = 0i32;
+    let mut end = 0;
+    for (digits, c) in text.chars().enumerate() {
+        match digit_value(c) {
+            Some(digit) if digits < max_digits => {
+                value = value * 10 + digit as i32;
+                end += c.len_utf8();
+            }
+            _ => break,
+        }
+    }
+    (end > 0).then(|| (value, &text[end..]))
+}
+
+/// Returns the value of the longest name found at the start of the given text, and the rest of the text
+fn parse_name<T>(
+    text: &str,
+    candidates: impl Iterator<Item = (&'static str, T)>,
+) -> Option<(T, &str)> {
+    candidates
+        .filter(|(name, _)| {
+            text.get(..name.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+        })
+        .max_by_key(|(name, _)| name.len())
+        .map(|(name, value)| (value, &text[name.len()..]))
+}
+
+fn unexpected(field: &'static str, rest: &str) -> DateFormatError {
+    DateFormatError::ExpectedField {
+        field,
+        rest: rest.into(),
+    }
+}
+
+fn months() -> impl Iterator<Item = Month> {
+    (0..12).map(|n| Month::January.nth_next(n))
+}
+
+fn weekdays() -> impl Iterator<Item = Weekday> {
+    (0..7).map(|n| Weekday::Sunday.nth_next(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rstest_utils::create_datetime;
+
+    use rstest::*;
+
+    const BUDDHIST: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: false,
+    };
+
+    fn create_date_format(
+        pattern: &str,
+        locale: Locale,
+        calendar_era: CalendarEra,
+        thai_digits: bool,
+    ) -> DateFormat {
+        DateFormatBuilder::default()
+            .pattern(pattern)
+            .locale(locale)
+            .calendar_era(calendar_era)
+            .thai_digits(thai_digits)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn format_default() {
+        assert_eq!(
+            "1 มกราคม 2567",
+            DateFormat::default().format(&create_datetime(2024, 1, 1, 0, 0))
+        );
+    }
+
+    #[rstest(
+        expected, date_format, datetime, //
+        case::thai_full("วันจันทร์ที่ 1 มกราคม พ.ศ. 2567", create_date_format("%Aที่ %e %B %E %Y", Locale::Thai, BUDDHIST, false), create_datetime(2024, 1, 1, 0, 0)),
+        case::thai_abbreviated("พฤ. 09 พ.ค. 67", create_date_format("%a %d %b 67", Locale::Thai, BUDDHIST, false), create_datetime(2024, 5, 9, 0, 0)),
+        case::thai_digits("๑๓/๐๔/๒๕๖๗ ๐๘:๓๐", create_date_format("%d/%m/%Y %H:%M", Locale::Thai, BUDDHIST, true), create_datetime(2024, 4, 13, 8, 30)),
+        case::english("Tuesday, 31 December 2024 CE", create_date_format("%A, %e %B %Y %E", Locale::English, CalendarEra::Christian, false), create_datetime(2024, 12, 31, 0, 0)),
+        case::april_new_year("15 ก.พ. 2472", create_date_format("%e %b %Y", Locale::Thai, CalendarEra::Buddhist { april_new_year: true }, false), create_datetime(1930, 2, 15, 0, 0)),
+        case::percent("100% 2024", create_date_format("100%% %Y", Locale::English, CalendarEra::Christian, false), create_datetime(2024, 1, 1, 0, 0)),
+    )]
+    fn format(expected: &str, date_format: DateFormat, datetime: PrimitiveDateTime) {
+        assert_eq!(expected, date_format.format(&datetime));
+    }
+
+    #[rstest(
+        expected, date_format, text, //
+        case::thai_full(create_datetime(2024, 1, 1, 0, 0), create_date_format("%Aที่ %e %B %E %Y", Locale::Thai, BUDDHIST, false), "วันจันทร์ที่ 1 มกราคม พ.ศ. 2567"),
+        case::thai_abbreviated(create_datetime(2024, 5, 9, 0, 0), create_date_format("%a %d %b %Y", Locale::Thai, BUDDHIST, false), "พฤ. 09 พ.ค. 2567"),
+        case::thai_digits(create_datetime(2024, 4, 13, 8, 30), create_date_format("%d/%m/%Y %H:%M", Locale::Thai, BUDDHIST, true), "๑๓/๐๔/๒๕๖๗ ๐๘:๓๐"),
+        case::thai_digits_not_required(create_datetime(2024, 4, 13, 8, 30), create_date_format("%d/%m/%Y %H:%M", Locale::Thai, BUDDHIST, true), "13/04/2567 08:30"),
+        case::english_case_insensitive(create_datetime(2024, 12, 31, 0, 0), create_date_format("%a %e %b %Y", Locale::English, CalendarEra::Christian, false), "tue 31 DEC 2024"),
+        case::flexible_whitespace(create_datetime(2024, 1, 1, 0, 0), create_date_format("%e %B %Y", Locale::Thai, BUDDHIST, false), "1   มกราคม 2567"),
+        case::april_new_year_before_april(create_datetime(1930, 2, 15, 0, 0), create_date_format("%e %b %Y", Locale::Thai, CalendarEra::Buddhist { april_new_year: true }, false), "15 ก.พ. 2472"),
+        case::april_new_year_from_april(create_datetime(1929, 4, 15, 0, 0), create_date_format("%e %b %Y", Locale::Thai, CalendarEra::Buddhist { april_new_year: true }, false), "15 เม.ย. 2472"),
+    )]
+    fn parse(expected: PrimitiveDateTime, date_format: DateFormat, text: &str) {
+        assert_eq!(Ok(expected), date_format.parse(text));
+    }
+
+    #[rstest(
+        expected, date_format, text, //
+        case::unknown_month(DateFormatError::ExpectedField { field: "month name", rest: "January 2567".into() }, create_date_format("%e %B %Y", Locale::Thai, BUDDHIST, false), "1 January 2567"),
+        case::invalid_day(Date::from_calendar_date(2024, Month::February, 30).unwrap_err().into(), create_date_format("%e %B %Y", Locale::Thai, BUDDHIST, false), "30 กุมภาพันธ์ 2567"),
+        case::wrong_weekday(DateFormatError::WrongWeekday { date: Date::from_calendar_date(2024, Month::January, 1).unwrap(), weekday: Weekday::Tuesday }, create_date_format("%A %e %B %Y", Locale::Thai, BUDDHIST, false), "วันอังคาร 1 มกราคม 2567"),
+        case::trailing_text(DateFormatError::TrailingText(" เวลา".into()), create_date_format("%e %B %Y", Locale::Thai, BUDDHIST, false), "1 มกราคม 2567 เวลา"),
+        case::missing_literal(DateFormatError::ExpectedLiteral { literal: '/', rest: "-01/2567".into() }, create_date_format("%d/%m/%Y", Locale::Thai, BUDDHIST, false), "01-01/2567"),
+        case::missing_year(DateFormatError::MissingDateField, create_date_format("%e %B", Locale::Thai, BUDDHIST, false), "1 มกราคม"),
+        case::invalid_hour(Time::from_hms(25, 0, 0).unwrap_err().into(), create_date_format("%e/%m/%Y %H:%M", Locale::Thai, BUDDHIST, false), "1/1/2567 25:00"),
+        case::april_new_year_shortened_year(DateFormatError::NotInYear { day: 15, month: Month::February, year: 2483 }, create_date_format("%e %b %Y", Locale::Thai, CalendarEra::Buddhist { april_new_year: true }, false), "15 ก.พ. 2483"),
+    )]
+    fn parse_invalid(expected: DateFormatError, date_format: DateFormat, text: &str) {
+        assert_eq!(Err(expected), date_format.parse(text));
+    }
+
+    #[rstest(
+        expected, pattern, //
+        case::unknown_field(DateFormatError::UnknownField('Q'), "%e %Q %Y"),
+        case::single_percent_at_end(DateFormatError::SinglePercent, "%e %B %Y %"),
+    )]
+    fn date_format_invalid_pattern(expected: DateFormatError, pattern: &str) {
+        assert_eq!(
+            expected,
+            DateFormatBuilder::default()
+                .pattern(pattern)
+                .build()
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            "expected month name at \"January 2567\"",
+            DateFormatError::ExpectedField {
+                field: "month name",
+                rest: "January 2567".into()
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "15 February does not exist in the year 2483",
+            DateFormatError::NotInYear {
+                day: 15,
+                month: Month::February,
+                year: 2483
+            }
+            .to_string()
+        );
+    }
+}
@@ -1,22 +1,111 @@
+use std::cmp::Ordering;
+
 /// Types of views for the datepicker.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+/// The discriminants of `Years`, `Months` and `Days` are kept from the first versions,
+/// the views are ordered from the largest to the smallest period, not by discriminant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DialogViewType {
-    /// YEARS_IN_YEAR_SELECTION Years, from a year which modulo `% 20 == 0`
+    /// YEARS_IN_YEAR_SELECTION Years, from a year of the `CalendarEra` which modulo `% 20 == 0`
     Years = 1,
+    /// 1 full year with the selection of a half year
+    HalfYears = 4,
+    /// 1 full year with the selection of a quarter
+    Quarters = 5,
     /// 1 full year with the selection of a month
     Months = 2,
+    /// 1 full month with the selection of a week, a row of days
+    Weeks = 6,
     /// 1 full month with the selection of a day
     #[default]
     Days = 3,
+    /// 1 full day with the selection of an hour
+    Hours = 7,
+    /// 1 full hour with the selection of a minute, by steps of `PickerConfig::minute_step`
+    Minutes = 8,
+}
+
+impl Ord for DialogViewType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for DialogViewType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl DialogViewType {
+    /// Returns the position of the view type from the largest to the smallest period
+    const fn rank(&self) -> u8 {
+        match self {
+            DialogViewType::Years => 0,
+            DialogViewType::HalfYears => 1,
+            DialogViewType::Quarters => 2,
+            DialogViewType::Months => 3,
+            DialogViewType::Weeks => 4,
+            DialogViewType::Days => 5,
+            DialogViewType::Hours => 6,
+            DialogViewType::Minutes => 7,
+        }
+    }
+
     /// Returns the larger view type, if such exists, otherwise returns None
     pub const fn larger_type(&self) -> Option<Self> {
         match self {
             DialogViewType::Years => None,
-            DialogViewType::Months => Some(DialogViewType::Years),
-            DialogViewType::Days => Some(DialogViewType::Months),
+            DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+                Some(DialogViewType::Years)
+            }
+            DialogViewType::Weeks | DialogViewType::Days => Some(DialogViewType::Months),
+            DialogViewType::Hours => Some(DialogViewType::Days),
+            DialogViewType::Minutes => Some(DialogViewType::Hours),
+        }
+    }
+
+    /// Returns the next smaller view type on the way to the given selection type,
+    /// if self is larger than the selection type, otherwise returns None.
+    /// Half years, quarters and months are all selected in a year, and weeks and days are both
+    /// selected in a month, so these views never zoom in to each other.
+    pub const fn smaller_type(&self, selection_type: &Self) -> Option<Self> {
+        match (self, selection_type) {
+            (DialogViewType::Years, DialogViewType::HalfYears) => Some(DialogViewType::HalfYears),
+            (DialogViewType::Years, DialogViewType::Quarters) => Some(DialogViewType::Quarters),
+            (
+                DialogViewType::Years,
+                DialogViewType::Months
+                | DialogViewType::Weeks
+                | DialogViewType::Days
+                | DialogViewType::Hours
+                | DialogViewType::Minutes,
+            ) => Some(DialogViewType::Months),
+            (DialogViewType::Months, DialogViewType::Weeks) => Some(DialogViewType::Weeks),
+            (
+                DialogViewType::Months,
+                DialogViewType::Days | DialogViewType::Hours | DialogViewType::Minutes,
+            ) => Some(DialogViewType::Days),
+            (DialogViewType::Days, DialogViewType::Hours | DialogViewType::Minutes) => {
+                Some(DialogViewType::Hours)
+            }
+            (DialogViewType::Hours, DialogViewType::Minutes) => Some(DialogViewType::Minutes),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the given selection type is self or is reached from self
+    /// by zooming in with `smaller_type`
+    pub const fn reaches(&self, selection_type: &Self) -> bool {
+        let mut view_type = *self;
+        loop {
+            if view_type as u8 == *selection_type as u8 {
+                return true;
+            }
+            match view_type.smaller_type(selection_type) {
+                Some(smaller_type) => view_type = smaller_type,
+                None => return false,
+            }
         }
     }
 }
@@ -30,10 +119,94 @@ mod tests {
     #[rstest(
         expected, input, //
         case::years(None, DialogViewType::Years),
+        case::half_years(Some(DialogViewType::Years), DialogViewType::HalfYears),
+        case::quarters(Some(DialogViewType::Years), DialogViewType::Quarters),
         case::months(Some(DialogViewType::Years), DialogViewType::Months),
+        case::weeks(Some(DialogViewType::Months), DialogViewType::Weeks),
         case::days(Some(DialogViewType::Months), DialogViewType::Days),
+        case::hours(Some(DialogViewType::Days), DialogViewType::Hours),
+        case::minutes(Some(DialogViewType::Hours), DialogViewType::Minutes),
     )]
     fn larger_type(expected: Option<DialogViewType>, input: DialogViewType) {
         assert_eq!(expected, input.larger_type());
     }
+
+    #[rstest(
+        expected, input, selection_type, //
+        case::years_to_years(None, DialogViewType::Years, DialogViewType::Years),
+        case::years_to_half_years(Some(DialogViewType::HalfYears), DialogViewType::Years, DialogViewType::HalfYears),
+        case::years_to_quarters(Some(DialogViewType::Quarters), DialogViewType::Years, DialogViewType::Quarters),
+        case::quarters_to_months(None, DialogViewType::Quarters, DialogViewType::Months),
+        case::years_to_months(Some(DialogViewType::Months), DialogViewType::Years, DialogViewType::Months),
+        case::years_to_days(Some(DialogViewType::Months), DialogViewType::Years, DialogViewType::Days),
+        case::months_to_months(None, DialogViewType::Months, DialogViewType::Months),
+        case::months_to_weeks(Some(DialogViewType::Weeks), DialogViewType::Months, DialogViewType::Weeks),
+        case::months_to_days(Some(DialogViewType::Days), DialogViewType::Months, DialogViewType::Days),
+        case::weeks_to_days(None, DialogViewType::Weeks, DialogViewType::Days),
+        case::days_to_days(None, DialogViewType::Days, DialogViewType::Days),
+        case::years_to_minutes(Some(DialogViewType::Months), DialogViewType::Years, DialogViewType::Minutes),
+        case::months_to_hours(Some(DialogViewType::Days), DialogViewType::Months, DialogViewType::Hours),
+        case::days_to_minutes(Some(DialogViewType::Hours), DialogViewType::Days, DialogViewType::Minutes),
+        case::hours_to_minutes(Some(DialogViewType::Minutes), DialogViewType::Hours, DialogViewType::Minutes),
+        case::minutes_to_minutes(None, DialogViewType::Minutes, DialogViewType::Minutes),
+        case::days_to_years(None, DialogViewType::Days, DialogViewType::Years),
+    )]
+    fn smaller_type(
+        expected: Option<DialogViewType>,
+        input: DialogViewType,
+        selection_type: DialogViewType,
+    ) {
+        assert_eq!(expected, input.smaller_type(&selection_type));
+    }
+
+    #[rstest(
+        expected, input, selection_type, //
+        case::same(true, DialogViewType::Weeks, DialogViewType::Weeks),
+        case::years_to_minutes(true, DialogViewType::Years, DialogViewType::Minutes),
+        case::months_to_weeks(true, DialogViewType::Months, DialogViewType::Weeks),
+        case::weeks_to_days(false, DialogViewType::Weeks, DialogViewType::Days),
+        case::years_to_quarters(true, DialogViewType::Years, DialogViewType::Quarters),
+        case::quarters_to_months(false, DialogViewType::Quarters, DialogViewType::Months),
+        case::half_years_to_quarters(false, DialogViewType::HalfYears, DialogViewType::Quarters),
+        case::weeks_to_hours(false, DialogViewType::Weeks, DialogViewType::Hours),
+        case::days_to_years(false, DialogViewType::Days, DialogViewType::Years),
+    )]
+    fn reaches(expected: bool, input: DialogViewType, selection_type: DialogViewType) {
+        assert_eq!(expected, input.reaches(&selection_type));
+    }
+
+    #[test]
+    fn discriminants() {
+        assert_eq!(1, DialogViewType::Years as u8);
+        assert_eq!(2, DialogViewType::Months as u8);
+        assert_eq!(3, DialogViewType::Days as u8);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut view_types = vec![
+            DialogViewType::Minutes,
+            DialogViewType::Days,
+            DialogViewType::Quarters,
+            DialogViewType::Years,
+            DialogViewType::Hours,
+            DialogViewType::Weeks,
+            DialogViewType::Months,
+            DialogViewType::HalfYears,
+        ];
+        view_types.sort();
+        assert_eq!(
+            vec![
+                DialogViewType::Years,
+                DialogViewType::HalfYears,
+                DialogViewType::Quarters,
+                DialogViewType::Months,
+                DialogViewType::Weeks,
+                DialogViewType::Days,
+                DialogViewType::Hours,
+                DialogViewType::Minutes,
+            ],
+            view_types
+        );
+    }
 }
@@ -0,0 +1,397 @@
+use time::{Date, Month, PrimitiveDateTime, error::ComponentRange};
+
+use crate::{
+    calendar_era::{BUDDHIST_ERA_JANUARY_NEW_YEAR, CalendarEra},
+    locale::Locale,
+    utils::try_from_ymd,
+    viewed_date::{ViewedDate, YEARS_IN_YEAR_SELECTION, YearNumber, year_group_start},
+};
+
+/// Years starting on the first day of `start_month`, a fiscal year is numbered
+/// by the calendar year in which it ends.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FiscalCalendar {
+    start_month: Month,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        Self::CALENDAR_YEAR
+    }
+}
+
+impl FiscalCalendar {
+    /// Fiscal years equal to the calendar years, starting on 1 January
+    pub const CALENDAR_YEAR: Self = Self::new(Month::January);
+
+    /// Fiscal years of the Thai government, starting on 1 October,
+    /// e.g. the fiscal year 2568 BE is from October 2024 to September 2025
+    pub const THAI_GOVERNMENT: Self = Self::new(Month::October);
+
+    pub const fn new(start_month: Month) -> Self {
+        Self { start_month }
+    }
+
+    /// Returns the month of the first day of the fiscal years
+    pub const fn start_month(&self) -> Month {
+        self.start_month
+    }
+
+    /// Returns true if the fiscal years are the calendar years
+    pub const fn is_calendar_year(&self) -> bool {
+        matches!(self.start_month, Month::January)
+    }
+
+    /// Returns the Gregorian number of the fiscal year the given date belongs to
+    pub fn fiscal_year(&self, date: &Date) -> YearNumber {
+        if !self.is_calendar_year() && date.month() as u8 >= self.start_month as u8 {
+            date.year() + 1
+        } else {
+            date.year()
+        }
+    }
+
+    /// Returns the first day of the given Gregorian fiscal year
+    pub fn first_day(&self, fiscal_year: YearNumber) -> Date {
+        self.try_first_day(fiscal_year)
+            .expect("invalid or out-of-range fiscal year")
+    }
+
+    /// Returns the first day of the given fiscal year,
+    /// or an error if it is out of the range of `Date`
+    pub fn try_first_day(&self, fiscal_year: YearNumber) -> Result<Date, ComponentRange> {
+        if self.is_calendar_year() {
+            try_from_ymd(fiscal_year, 1, 1)
+        } else {
+            try_from_ymd(fiscal_year - 1, self.start_month as u8, 1)
+        }
+    }
+
+    /// Returns a datetime with the first day of the fiscal year including the given datetime,
+    /// `Date::MIN` if the fiscal year starts before it
+    pub fn first_day_of_year(&self, date: &PrimitiveDateTime) -> PrimitiveDateTime {
+        let first_day = self
+            .try_first_day(self.fiscal_year(&date.date()))
+            .unwrap_or(Date::MIN);
+        PrimitiveDateTime::new(first_day, date.time())
+    }
+
+    /// Returns a datetime with the first day of the previous fiscal year,
+    /// the given datetime if it is out of the range of `Date`
+    pub fn previous_year(&self, date: &PrimitiveDateTime) -> PrimitiveDateTime {
+        let fiscal_year = self.fiscal_year(&date.date()) - 1;
+        self.try_first_day(fiscal_year)
+            .map(|first_day| PrimitiveDateTime::new(first_day, date.time()))
+            .unwrap_or(*date)
+    }
+
+    /// Returns a datetime with the first day of the next fiscal year,
+    /// the given datetime if it is out of the range of `Date`
+    pub fn next_year(&self, date: &PrimitiveDateTime) -> PrimitiveDateTime {
+        let fiscal_year = self.fiscal_year(&date.date()) + 1;
+        self.try_first_day(fiscal_year)
+            .map(|first_day| PrimitiveDateTime::new(first_day, date.time()))
+            .unwrap_or(*date)
+    }
+
+    /// Returns a datetime with the first day of the last fiscal year of the previous year group,
+    /// the year groups are aligned on the fiscal years labelled in the given era,
+    /// the given datetime if it is out of the range of `Date`
+    pub fn previous_year_group(
+        &self,
+        era: &CalendarEra,
+        date: &PrimitiveDateTime,
+    ) -> PrimitiveDateTime {
+        if self.is_calendar_year() {
+            return date.previous_year_group_in(era);
+        }
+        self.first_day_of_era_year_or(era, date, self.year_group_start(era, date) - 1)
+    }
+
+    /// Returns a datetime with the first day of the first fiscal year of the next year group,
+    /// the year groups are aligned on the fiscal years labelled in the given era,
+    /// the given datetime if it is out of the range of `Date`
+    pub fn next_year_group(
+        &self,
+        era: &CalendarEra,
+        date: &PrimitiveDateTime,
+    ) -> PrimitiveDateTime {
+        if self.is_calendar_year() {
+            return date.next_year_group_in(era);
+        }
+        let year = self.year_group_start(era, date) + YEARS_IN_YEAR_SELECTION;
+        self.first_day_of_era_year_or(era, date, year)
+    }
+
+    /// Returns the first year of the given era of the year group of fiscal years including the given datetime
+    fn year_group_start(&self, era: &CalendarEra, date: &PrimitiveDateTime) -> YearNumber {
+        year_group_start(self.year_label(&date.date(), era))
+    }
+
+    /// Returns a datetime with the first day of the fiscal year labelled by the given year of the era,
+    /// the given datetime if it is out of the range of `Date`
+    fn first_day_of_era_year_or(
+        &self,
+        era: &CalendarEra,
+        date: &PrimitiveDateTime,
+        era_year: YearNumber,
+    ) -> PrimitiveDateTime {
+        self.try_first_day(self.fiscal_year_of_label(era_year, era))
+            .map(|first_day| PrimitiveDateTime::new(first_day, date.time()))
+            .unwrap_or(*date)
+    }
+
+    /// Returns a datetime with the first day of the fiscal quarter including the given datetime,
+    /// the quarters are counted from `start_month`
+    pub fn first_day_of_quarter(&self, date: &PrimitiveDateTime) -> PrimitiveDateTime {
+        self.first_day_of_part(date, 3)
+    }
+
+    /// Returns a datetime with the first day of the fiscal half year including the given datetime,
+    /// the half years are counted from `start_month`
+    pub fn first_day_of_half_year(&self, date: &PrimitiveDateTime) -> PrimitiveDateTime {
+        self.first_day_of_part(date, 6)
+    }
+
+    /// Returns a datetime with the first day of the part of the given number of months
+    /// of the fiscal year including the given datetime
+    fn first_day_of_part(&self, date: &PrimitiveDateTime, months: i32) -> PrimitiveDateTime {
+        let month_index = (date.month() as i32 - self.start_month as i32).rem_euclid(12);
+        date.first_day_of_month()
+            .add_months(-(month_index % months))
+    }
+
+    /// Returns datetimes with the first day of the 12 months of the fiscal year
+    /// including the given datetime, without the months out of the range of `Date`
+    pub fn months(&self, date: &PrimitiveDateTime) -> Vec<PrimitiveDateTime> {
+        let fiscal_year = self.fiscal_year(&date.date());
+        std::iter::successors(Some(self.first_day_of_year(date)), |month| {
+            Some(month.next_month()).filter(|next| next != month)
+        })
+        .take_while(|month| self.fiscal_year(&month.date()) == fiscal_year)
+        .collect()
+    }
+
+    /// Returns the number of the fiscal year the given date belongs to, as a year of the given era,
+    /// the year of the era of the last day of the fiscal year, so the year of the era of the date
+    /// if the fiscal years are the calendar years
+    pub fn year_label(&self, date: &Date, era: &CalendarEra) -> YearNumber {
+        if self.is_calendar_year() {
+            era.year(date)
+        } else {
+            let fiscal_year = self.fiscal_year(date);
+            era.from_gregorian_year(fiscal_year)
+                - YearNumber::from(self.ends_before_april_new_year(fiscal_year, era))
+        }
+    }
+
+    /// Returns the Gregorian number of the fiscal year labelled by the given year of the given era,
+    /// the inverse of `year_label`
+    pub fn fiscal_year_of_label(&self, era_year: YearNumber, era: &CalendarEra) -> YearNumber {
+        let fiscal_year = era.to_gregorian_year(era_year);
+        if self.ends_before_april_new_year(fiscal_year + 1, era) {
+            fiscal_year + 1
+        } else {
+            fiscal_year
+        }
+    }
+
+    /// Returns the Gregorian numbers of the fiscal years of the year group of the given era
+    /// including the given date, the fiscal years out of the range of `Date` included
+    pub fn year_group_range(
+        &self,
+        era: &CalendarEra,
+        date: &Date,
+    ) -> impl Iterator<Item = YearNumber> {
+        let group_start = year_group_start(self.year_label(date, era));
+        (group_start..group_start + YEARS_IN_YEAR_SELECTION)
+            .map(move |era_year| self.fiscal_year_of_label(era_year, era))
+    }
+
+    /// Returns true if the given fiscal year ends between January and March of a year before 1941,
+    /// so it is labelled by the previous year of an era following the former April new year
+    fn ends_before_april_new_year(&self, fiscal_year: YearNumber, era: &CalendarEra) -> bool {
+        matches!(
+            era,
+            CalendarEra::Buddhist {
+                april_new_year: true
+            }
+        ) && !self.is_calendar_year()
+            && self.start_month as u8 <= Month::April as u8
+            && fiscal_year < BUDDHIST_ERA_JANUARY_NEW_YEAR
+    }
+
+    /// Returns the display name of the fiscal year the given date belongs to,
+    /// like "ปีงบประมาณ พ.ศ. 2568" or "Fiscal year 2025 CE"
+    pub fn format_year(&self, date: &Date, era: &CalendarEra, locale: &Locale) -> String {
+        let year = self.year_label(date, era);
+        match locale {
+            Locale::Thai => format!(
+                "{} {} {year}",
+                locale.fiscal_year_name(),
+                locale.era_abbreviation(era)
+            ),
+            Locale::English => format!(
+                "{} {year} {}",
+                locale.fiscal_year_name(),
+                locale.era_abbreviation(era)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::{date, datetime};
+
+    const BUDDHIST: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: false,
+    };
+    const BUDDHIST_APRIL: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: true,
+    };
+
+    #[rstest(
+        expected, fiscal_calendar, date, //
+        case::calendar_year(2024, FiscalCalendar::CALENDAR_YEAR, date!(2024 - 12 - 31)),
+        case::before_start(2025, FiscalCalendar::THAI_GOVERNMENT, date!(2025 - 09 - 30)),
+        case::at_start(2025, FiscalCalendar::THAI_GOVERNMENT, date!(2024 - 10 - 01)),
+        case::after_start(2025, FiscalCalendar::THAI_GOVERNMENT, date!(2024 - 12 - 31)),
+    )]
+    fn fiscal_year(expected: YearNumber, fiscal_calendar: FiscalCalendar, date: Date) {
+        assert_eq!(expected, fiscal_calendar.fiscal_year(&date));
+    }
+
+    #[rstest(
+        expected, fiscal_calendar, fiscal_year, //
+        case::calendar_year(date!(2025 - 01 - 01), FiscalCalendar::CALENDAR_YEAR, 2025),
+        case::thai_government(date!(2024 - 10 - 01), FiscalCalendar::THAI_GOVERNMENT, 2025),
+    )]
+    fn first_day(expected: Date, fiscal_calendar: FiscalCalendar, fiscal_year: YearNumber) {
+        assert_eq!(expected, fiscal_calendar.first_day(fiscal_year));
+    }
+
+    #[rstest(
+        expected_previous, expected_next, fiscal_calendar, date, //
+        case::calendar_year(datetime!(2023-01-01 10:00), datetime!(2025-01-01 10:00), FiscalCalendar::CALENDAR_YEAR, datetime!(2024-11-15 10:00)),
+        case::before_start(datetime!(2023-10-01 10:00), datetime!(2025-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(2025-03-15 10:00)),
+        case::after_start(datetime!(2023-10-01 10:00), datetime!(2025-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(2024-11-15 10:00)),
+        case::at_min(datetime!(-9999-03-15 10:00), datetime!(-9999-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(-9999-03-15 10:00)),
+        case::at_max(datetime!(9998-10-01 10:00), datetime!(9999-11-15 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(9999-11-15 10:00)),
+    )]
+    fn previous_and_next_year(
+        expected_previous: PrimitiveDateTime,
+        expected_next: PrimitiveDateTime,
+        fiscal_calendar: FiscalCalendar,
+        date: PrimitiveDateTime,
+    ) {
+        assert_eq!(expected_previous, fiscal_calendar.previous_year(&date));
+        assert_eq!(expected_next, fiscal_calendar.next_year(&date));
+    }
+
+    #[rstest(
+        expected_quarter, expected_half_year, fiscal_calendar, date, //
+        case::calendar_year(datetime!(2024-10-01 10:00), datetime!(2024-07-01 10:00), FiscalCalendar::CALENDAR_YEAR, datetime!(2024-11-15 10:00)),
+        case::thai_government(datetime!(2024-10-01 10:00), datetime!(2024-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(2024-11-15 10:00)),
+        case::thai_government_second_half(datetime!(2025-04-01 10:00), datetime!(2025-04-01 10:00), FiscalCalendar::THAI_GOVERNMENT, datetime!(2025-05-15 10:00)),
+        case::november(datetime!(2025-02-01 10:00), datetime!(2024-11-01 10:00), FiscalCalendar::new(Month::November), datetime!(2025-03-15 10:00)),
+    )]
+    fn first_day_of_quarter_and_half_year(
+        expected_quarter: PrimitiveDateTime,
+        expected_half_year: PrimitiveDateTime,
+        fiscal_calendar: FiscalCalendar,
+        date: PrimitiveDateTime,
+    ) {
+        assert_eq!(
+            expected_quarter,
+            fiscal_calendar.first_day_of_quarter(&date)
+        );
+        assert_eq!(
+            expected_half_year,
+            fiscal_calendar.first_day_of_half_year(&date)
+        );
+    }
+
+    #[rstest(
+        expected_previous, expected_next, fiscal_calendar, era, date, //
+        case::calendar_year(datetime!(2019-01-01 10:00), datetime!(2040-01-01 10:00), FiscalCalendar::CALENDAR_YEAR, CalendarEra::Christian, datetime!(2039-10-15 10:00)),
+        case::october(datetime!(2038-10-01 10:00), datetime!(2059-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, CalendarEra::Christian, datetime!(2039-10-15 10:00)),
+        case::october_before_start(datetime!(2018-10-01 10:00), datetime!(2039-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, CalendarEra::Christian, datetime!(2039-09-30 10:00)),
+        case::october_buddhist(datetime!(2015-10-01 10:00), datetime!(2036-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, BUDDHIST, datetime!(2024-10-15 10:00)),
+        case::april_before_1941(datetime!(1916-04-01 10:00), datetime!(1937-04-01 10:00), FiscalCalendar::new(Month::April), BUDDHIST_APRIL, datetime!(1930-06-15 10:00)),
+        case::at_min(datetime!(-9999-03-15 10:00), datetime!(-9981-10-01 10:00), FiscalCalendar::THAI_GOVERNMENT, CalendarEra::Christian, datetime!(-9999-03-15 10:00)),
+        case::at_max(datetime!(9998-10-01 10:00), datetime!(9999-11-15 10:00), FiscalCalendar::THAI_GOVERNMENT, CalendarEra::Christian, datetime!(9999-11-15 10:00)),
+    )]
+    fn previous_and_next_year_group(
+        expected_previous: PrimitiveDateTime,
+        expected_next: PrimitiveDateTime,
+        fiscal_calendar: FiscalCalendar,
+        era: CalendarEra,
+        date: PrimitiveDateTime,
+    ) {
+        assert_eq!(
+            expected_previous,
+            fiscal_calendar.previous_year_group(&era, &date)
+        );
+        assert_eq!(expected_next, fiscal_calendar.next_year_group(&era, &date));
+    }
+
+    #[rstest(
+        expected, fiscal_calendar, era, date, //
+        case::october(2474, FiscalCalendar::THAI_GOVERNMENT, BUDDHIST_APRIL, date!(1930 - 10 - 01)),
+        case::april_christian(1931, FiscalCalendar::new(Month::April), CalendarEra::Christian, date!(1930 - 04 - 01)),
+        case::april_buddhist(2474, FiscalCalendar::new(Month::April), BUDDHIST, date!(1930 - 04 - 01)),
+        case::april_before_1941(2473, FiscalCalendar::new(Month::April), BUDDHIST_APRIL, date!(1930 - 04 - 01)),
+        case::april_end_before_1941(2473, FiscalCalendar::new(Month::April), BUDDHIST_APRIL, date!(1931 - 03 - 31)),
+        case::april_from_1941(2484, FiscalCalendar::new(Month::April), BUDDHIST_APRIL, date!(1940 - 04 - 01)),
+        case::february_before_1941(2473, FiscalCalendar::new(Month::February), BUDDHIST_APRIL, date!(1930 - 02 - 01)),
+    )]
+    fn year_label(
+        expected: YearNumber,
+        fiscal_calendar: FiscalCalendar,
+        era: CalendarEra,
+        date: Date,
+    ) {
+        assert_eq!(expected, fiscal_calendar.year_label(&date, &era));
+        let fiscal_year = fiscal_calendar.fiscal_year(&date);
+        assert_eq!(
+            fiscal_year,
+            fiscal_calendar.fiscal_year_of_label(expected, &era)
+        );
+    }
+
+    #[test]
+    fn months() {
+        let months = FiscalCalendar::THAI_GOVERNMENT.months(&datetime!(2025-03-15 00:00));
+        assert_eq!(12, months.len());
+        assert_eq!(datetime!(2024-10-01 00:00), months[0]);
+        assert_eq!(datetime!(2025-09-01 00:00), months[11]);
+    }
+
+    #[test]
+    fn months_at_edges() {
+        let months = FiscalCalendar::THAI_GOVERNMENT.months(&datetime!(-9999-03-15 00:00));
+        assert_eq!(9, months.len());
+        assert_eq!(datetime!(-9999-01-01 00:00), months[0]);
+        let months = FiscalCalendar::THAI_GOVERNMENT.months(&datetime!(9999-11-15 00:00));
+        assert_eq!(3, months.len());
+        assert_eq!(datetime!(9999-12-01 00:00), months[2]);
+    }
+
+    #[rstest(
+        expected, era, locale, //
+        case::thai_buddhist("ปีงบประมาณ พ.ศ. 2568", BUDDHIST, Locale::Thai),
+        case::english_christian("Fiscal year 2025 CE", CalendarEra::Christian, Locale::English),
+        case::english_buddhist("Fiscal year 2568 BE", BUDDHIST, Locale::English),
+    )]
+    fn format_year(expected: &str, era: CalendarEra, locale: Locale) {
+        assert_eq!(
+            expected,
+            FiscalCalendar::THAI_GOVERNMENT.format_year(&date!(2024 - 10 - 01), &era, &locale)
+        );
+    }
+}
@@ -0,0 +1,476 @@
+use derive_builder::Builder;
+use derive_getters::Getters;
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    config::{PickerConfig, date_constraints::HasDateConstraints, error::ConfigError},
+    dialog_view_type::DialogViewType,
+    time_slots::floor_to_time_slot,
+    utils::is_forbidden_in_view,
+    viewed_date::{ViewedDate, YEARS_IN_YEAR_SELECTION, days_since, year_group_start},
+};
+
+/// Maximal number of forbidden cells skipped by a single key press
+pub const MAX_SKIPPED_CELLS: u16 = 400;
+
+/// Key moving the focused cell of a grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NavigationKey {
+    /// Previous cell
+    Left,
+    /// Next cell
+    Right,
+    /// Same column in the previous row
+    Up,
+    /// Same column in the next row
+    Down,
+    /// First cell of the row
+    Home,
+    /// Last cell of the row
+    End,
+    /// Same cell in the previous page: year group, year, month, day or hour
+    PageUp,
+    /// Same cell in the next page: year group, year, month, day or hour
+    PageDown,
+    /// Same cell in the previous page of the larger view type, a century in the years view
+    ShiftPageUp,
+    /// Same cell in the next page of the larger view type, a century in the years view
+    ShiftPageDown,
+}
+
+impl NavigationKey {
+    /// Returns the direction to skip the forbidden cells, -1 towards the past, 1 towards the future
+    const fn direction(&self) -> i64 {
+        match self {
+            NavigationKey::Left
+            | NavigationKey::Up
+            | NavigationKey::End
+            | NavigationKey::PageUp
+            | NavigationKey::ShiftPageUp => -1,
+            NavigationKey::Right
+            | NavigationKey::Down
+            | NavigationKey::Home
+            | NavigationKey::PageDown
+            | NavigationKey::ShiftPageDown => 1,
+        }
+    }
+}
+
+/// Options of the keyboard navigation, the numbers of columns must match the displayed grids.
+#[derive(Clone, Debug, Builder, Getters)]
+#[builder(default)]
+#[builder(build_fn(validate = "Self::validate", error = "ConfigError"))]
+pub struct KeyboardOptions {
+    /// Number of columns of the months grid, a divisor of 12
+    month_columns: u8,
+
+    /// Number of columns of the years grid, a divisor of `YEARS_IN_YEAR_SELECTION`
+    year_columns: u8,
+
+    /// Number of columns of the hours grid, a divisor of 24
+    hour_columns: u8,
+
+    /// Number of columns of the minutes grid
+    minute_columns: u8,
+
+    /// If true, the focus does not move to a forbidden cell,
+    /// otherwise the forbidden cells are skipped in the direction of the key
+    stop_at_forbidden: bool,
+}
+
+impl Default for KeyboardOptions {
+    fn default() -> Self {
+        Self {
+            month_columns: 3,
+            year_columns: 4,
+            hour_columns: 6,
+            minute_columns: 4,
+            stop_at_forbidden: false,
+        }
+    }
+}
+
+impl KeyboardOptionsBuilder {
+    fn validate(&self) -> Result<(), ConfigError> {
+        let check = |option: &'static str, columns: Option<u8>, cells: Option<u8>| match columns {
+            Some(columns) if columns == 0 || cells.is_some_and(|cells| cells % columns != 0) => {
+                Err(ConfigError::InvalidKeyboardOption {
+                    option,
+                    columns,
+                    cells,
+                })
+            }
+            _ => Ok(()),
+        };
+        check("month_columns", self.month_columns, Some(12))?;
+        check(
+            "year_columns",
+            self.year_columns,
+            Some(YEARS_IN_YEAR_SELECTION as u8),
+        )?;
+        check("hour_columns", self.hour_columns, Some(24))?;
+        check("minute_columns", self.minute_columns, None)
+    }
+}
+
+/// Returns the cell focused after pressing the given key on the focused cell of the given view type,
+/// None if the focus can not move to an allowed cell.
+/// A week cell is its first day and a minute cell is rounded down to a step of `PickerConfig::minute_step`.
+pub fn move_focus<T>(
+    config: &PickerConfig<T>,
+    options: &KeyboardOptions,
+    view_type: &DialogViewType,
+    focused: &PrimitiveDateTime,
+    key: NavigationKey,
+) -> Option<PrimitiveDateTime>
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    let focused = normalize(config, view_type, focused);
+    let mut target = normalize(
+        config,
+        view_type,
+        &target(config, options, view_type, &focused, key),
+    );
+    for _ in 0..MAX_SKIPPED_CELLS {
+        if !is_forbidden_in_view(config, view_type, &target) {
+            return Some(target);
+        }
+        if options.stop_at_forbidden {
+            return None;
+        }
+        let next = shift(config, view_type, &target, key.direction());
+        // stop at the edges of the range of `Date`
+        if next == target {
+            return None;
+        }
+        target = next;
+    }
+    None
+}
+
+/// Returns the cell of the given view type including the given datetime
+pub(crate) fn normalize<T>(
+    config: &PickerConfig<T>,
+    view_type: &DialogViewType,
+    date: &PrimitiveDateTime,
+) -> PrimitiveDateTime
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    match view_type {
+        DialogViewType::Weeks => date.week_start(*config.first_weekday()),
+        DialogViewType::Minutes => floor_to_time_slot(date, *config.minute_step()),
+        _ => *date,
+    }
+}
+
+/// Returns the cell the given number of cells later, or earlier if negative,
+/// the given cell at the edges of the range of `Date`
+fn shift<T>(
+    config: &PickerConfig<T>,
+    view_type: &DialogViewType,
+    cell: &PrimitiveDateTime,
+    cells: i64,
+) -> PrimitiveDateTime
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    let add = |duration: Duration| cell.checked_add(duration).unwrap_or(*cell);
+    let months =
+        |months: i64| i32::try_from(months).map_or(*cell, |months| cell.add_months(months));
+    match view_type {
+        DialogViewType::Years => months(cells * 12),
+        DialogViewType::HalfYears => months(cells * 6),
+        DialogViewType::Quarters => months(cells * 3),
+        DialogViewType::Months => months(cells),
+        DialogViewType::Weeks => add(Duration::weeks(cells)),
+        DialogViewType::Days => add(Duration::days(cells)),
+        DialogViewType::Hours => add(Duration::hours(cells)),
+        DialogViewType::Minutes => add(Duration::minutes(cells * i64::from(*config.minute_step()))),
+    }
+}
+
+/// Returns the number of columns of the grid of the given view type
+fn columns<T>(
+    config: &PickerConfig<T>,
+    options: &KeyboardOptions,
+    view_type: &DialogViewType,
+) -> i64
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    let columns = match view_type {
+        DialogViewType::Years => options.year_columns,
+        DialogViewType::HalfYears => 2,
+        DialogViewType::Quarters => 4,
+        DialogViewType::Months => options.month_columns,
+        DialogViewType::Weeks => 1,
+        DialogViewType::Days => 7,
+        DialogViewType::Hours => options.hour_columns,
+        DialogViewType::Minutes => options.minute_columns.min(60 / *config.minute_step()),
+    };
+    columns.into()
+}
+
+/// Returns the index of the given cell in its page
+fn index<T>(config: &PickerConfig<T>, view_type: &DialogViewType, cell: &PrimitiveDateTime) -> i64
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    // the months of the year views start on the first month of the fiscal year
+    let month = (i64::from(cell.month() as u8)
+        - i64::from(config.fiscal_calendar().start_month() as u8))
+    .rem_euclid(12);
+    let year = config
+        .fiscal_calendar()
+        .year_label(&cell.date(), config.calendar_era());
+    match view_type {
+        DialogViewType::Years => i64::from(year - year_group_start(year)),
+        DialogViewType::HalfYears => month / 6,
+        DialogViewType::Quarters => month / 3,
+        DialogViewType::Months => month,
+        DialogViewType::Weeks => 0,
+        DialogViewType::Days => days_since(cell.weekday(), *config.first_weekday()).into(),
+        DialogViewType::Hours => cell.hour().into(),
+        DialogViewType::Minutes => (cell.minute() / config.minute_step()).into(),
+    }
+}
+
+/// Returns the cell targeted by the given key, before skipping the forbidden cells
+fn target<T>(
+    config: &PickerConfig<T>,
+    options: &KeyboardOptions,
+    view_type: &DialogViewType,
+    focused: &PrimitiveDateTime,
+    key: NavigationKey,
+) -> PrimitiveDateTime
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    let columns = columns(config, options, view_type);
+    let column = index(config, view_type, focused) % columns;
+    let page = |pages: i32| match view_type {
+        DialogViewType::Years => focused.add_years(pages.saturating_mul(YEARS_IN_YEAR_SELECTION)),
+        DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+            focused.add_years(pages)
+        }
+        DialogViewType::Weeks | DialogViewType::Days => focused.add_months(pages),
+        DialogViewType::Hours => focused
+            .checked_add(Duration::days(pages.into()))
+            .unwrap_or(*focused),
+        DialogViewType::Minutes => focused
+            .checked_add(Duration::hours(pages.into()))
+            .unwrap_or(*focused),
+    };
+    let larger_page = |pages: i32| match view_type {
+        DialogViewType::Years => focused.add_years(pages.saturating_mul(100)),
+        DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+            focused.add_years(pages.saturating_mul(YEARS_IN_YEAR_SELECTION))
+        }
+        DialogViewType::Weeks | DialogViewType::Days => focused.add_years(pages),
+        DialogViewType::Hours => focused.add_months(pages),
+        DialogViewType::Minutes => focused
+            .checked_add(Duration::days(pages.into()))
+            .unwrap_or(*focused),
+    };
+    match (key, view_type) {
+        (NavigationKey::Left, _) => shift(config, view_type, focused, -1),
+        (NavigationKey::Right, _) => shift(config, view_type, focused, 1),
+        (NavigationKey::Up, _) => shift(config, view_type, focused, -columns),
+        (NavigationKey::Down, _) => shift(config, view_type, focused, columns),
+        // the weeks view is a single column, Home and End move to the first and last weeks of the month
+        (NavigationKey::Home, DialogViewType::Weeks) => focused.first_day_of_month(),
+        (NavigationKey::End, DialogViewType::Weeks) => focused
+            .next_month_same_day()
+            .first_day_of_month()
+            .checked_sub(Duration::days(1))
+            .filter(|last_day| last_day.month() == focused.month())
+            .unwrap_or(*focused),
+        (NavigationKey::Home, _) => shift(config, view_type, focused, -column),
+        (NavigationKey::End, _) => shift(config, view_type, focused, columns - 1 - column),
+        (NavigationKey::PageUp, _) => page(-1),
+        (NavigationKey::PageDown, _) => page(1),
+        (NavigationKey::ShiftPageUp, _) => larger_page(-1),
+        (NavigationKey::ShiftPageDown, _) => larger_page(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        PickerConfigBuilder,
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+    };
+
+    use rstest::*;
+    use time::{Date, Time, Weekday, macros::datetime};
+
+    fn create_config(date_constraints: DateConstraints) -> PickerConfig<DateConstraints> {
+        PickerConfigBuilder::default()
+            .date_constraints(date_constraints)
+            .build()
+            .unwrap()
+    }
+
+    #[rstest(
+        expected, view_type, key, //
+        case::days_left(datetime!(2024-05-14 10:00), DialogViewType::Days, NavigationKey::Left),
+        case::days_right(datetime!(2024-05-16 10:00), DialogViewType::Days, NavigationKey::Right),
+        case::days_up(datetime!(2024-05-08 10:00), DialogViewType::Days, NavigationKey::Up),
+        case::days_down(datetime!(2024-05-22 10:00), DialogViewType::Days, NavigationKey::Down),
+        case::days_home(datetime!(2024-05-12 10:00), DialogViewType::Days, NavigationKey::Home),
+        case::days_end(datetime!(2024-05-18 10:00), DialogViewType::Days, NavigationKey::End),
+        case::days_page_up(datetime!(2024-04-15 10:00), DialogViewType::Days, NavigationKey::PageUp),
+        case::days_page_down(datetime!(2024-06-15 10:00), DialogViewType::Days, NavigationKey::PageDown),
+        case::days_shift_page_up(datetime!(2023-05-15 10:00), DialogViewType::Days, NavigationKey::ShiftPageUp),
+        case::days_shift_page_down(datetime!(2025-05-15 10:00), DialogViewType::Days, NavigationKey::ShiftPageDown),
+        case::weeks_up(datetime!(2024-05-05 10:00), DialogViewType::Weeks, NavigationKey::Up),
+        case::weeks_home(datetime!(2024-04-28 10:00), DialogViewType::Weeks, NavigationKey::Home),
+        case::weeks_end(datetime!(2024-05-26 10:00), DialogViewType::Weeks, NavigationKey::End),
+        case::months_left(datetime!(2024-04-15 10:00), DialogViewType::Months, NavigationKey::Left),
+        case::months_up(datetime!(2024-02-15 10:00), DialogViewType::Months, NavigationKey::Up),
+        case::months_down(datetime!(2024-08-15 10:00), DialogViewType::Months, NavigationKey::Down),
+        case::months_home(datetime!(2024-04-15 10:00), DialogViewType::Months, NavigationKey::Home),
+        case::months_end(datetime!(2024-06-15 10:00), DialogViewType::Months, NavigationKey::End),
+        case::months_page_up(datetime!(2023-05-15 10:00), DialogViewType::Months, NavigationKey::PageUp),
+        case::months_shift_page_down(datetime!(2044-05-15 10:00), DialogViewType::Months, NavigationKey::ShiftPageDown),
+        case::quarters_up(datetime!(2023-05-15 10:00), DialogViewType::Quarters, NavigationKey::Up),
+        case::quarters_end(datetime!(2024-11-15 10:00), DialogViewType::Quarters, NavigationKey::End),
+        case::years_up(datetime!(2020-05-15 10:00), DialogViewType::Years, NavigationKey::Up),
+        case::years_down(datetime!(2028-05-15 10:00), DialogViewType::Years, NavigationKey::Down),
+        case::years_end(datetime!(2027-05-15 10:00), DialogViewType::Years, NavigationKey::End),
+        case::years_page_up(datetime!(2004-05-15 10:00), DialogViewType::Years, NavigationKey::PageUp),
+        case::years_shift_page_up(datetime!(1924-05-15 10:00), DialogViewType::Years, NavigationKey::ShiftPageUp),
+        case::hours_down(datetime!(2024-05-15 16:00), DialogViewType::Hours, NavigationKey::Down),
+        case::hours_home(datetime!(2024-05-15 06:00), DialogViewType::Hours, NavigationKey::Home),
+        case::minutes_left(datetime!(2024-05-15 09:55), DialogViewType::Minutes, NavigationKey::Left),
+        case::minutes_page_down(datetime!(2024-05-15 11:00), DialogViewType::Minutes, NavigationKey::PageDown),
+    )]
+    fn move_focus_in(expected: PrimitiveDateTime, view_type: DialogViewType, key: NavigationKey) {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .minute_step(5)
+            .build()
+            .unwrap();
+        assert_eq!(
+            Some(expected),
+            move_focus(
+                &config,
+                &KeyboardOptions::default(),
+                &view_type,
+                &datetime!(2024-05-15 10:00),
+                key
+            )
+        );
+    }
+
+    #[rstest(
+        expected, focused, key, stop_at_forbidden, //
+        case::skip_forward(Some(datetime!(2024-05-20 10:00)), datetime!(2024-05-17 10:00), NavigationKey::Right, false),
+        case::skip_backward(Some(datetime!(2024-05-17 10:00)), datetime!(2024-05-20 10:00), NavigationKey::Left, false),
+        case::skip_home_forward(Some(datetime!(2024-05-13 10:00)), datetime!(2024-05-15 10:00), NavigationKey::Home, false),
+        case::allowed(Some(datetime!(2024-05-16 10:00)), datetime!(2024-05-15 10:00), NavigationKey::Right, true),
+        case::stop(None, datetime!(2024-05-17 10:00), NavigationKey::Right, true),
+    )]
+    fn move_focus_forbidden(
+        expected: Option<PrimitiveDateTime>,
+        focused: PrimitiveDateTime,
+        key: NavigationKey,
+        stop_at_forbidden: bool,
+    ) {
+        let config = create_config(
+            DateConstraintsBuilder::default()
+                .disabled_weekdays([Weekday::Saturday, Weekday::Sunday].into_iter().collect())
+                .build()
+                .unwrap(),
+        );
+        let options = KeyboardOptionsBuilder::default()
+            .stop_at_forbidden(stop_at_forbidden)
+            .build()
+            .unwrap();
+        assert_eq!(
+            expected,
+            move_focus(&config, &options, &DialogViewType::Days, &focused, key)
+        );
+    }
+
+    #[rstest(
+        key, //
+        case::left(NavigationKey::Left),
+        case::up(NavigationKey::Up),
+    )]
+    fn move_focus_stops_at_min(key: NavigationKey) {
+        let config = create_config(DateConstraints::default());
+        let focused = PrimitiveDateTime::new(Date::MIN, Time::MIDNIGHT);
+        assert_eq!(
+            Some(focused),
+            move_focus(
+                &config,
+                &KeyboardOptions::default(),
+                &DialogViewType::Days,
+                &focused,
+                key
+            )
+        );
+    }
+
+    #[test]
+    fn move_focus_stops_after_forbidden_cells() {
+        let config = create_config(
+            DateConstraintsBuilder::default()
+                .max_datetime(datetime!(2024-05-15 23:59))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            None,
+            move_focus(
+                &config,
+                &KeyboardOptions::default(),
+                &DialogViewType::Days,
+                &datetime!(2024-05-15 10:00),
+                NavigationKey::Right
+            )
+        );
+    }
+
+    #[test]
+    fn options_validation() {
+        assert!(KeyboardOptionsBuilder::default().build().is_ok());
+        assert_eq!(
+            ConfigError::InvalidKeyboardOption {
+                option: "month_columns",
+                columns: 5,
+                cells: Some(12)
+            },
+            KeyboardOptionsBuilder::default()
+                .month_columns(5)
+                .build()
+                .unwrap_err()
+        );
+        assert!(
+            KeyboardOptionsBuilder::default()
+                .year_columns(3)
+                .build()
+                .is_err()
+        );
+        assert!(
+            KeyboardOptionsBuilder::default()
+                .hour_columns(0)
+                .build()
+                .is_err()
+        );
+        assert_eq!(
+            ConfigError::InvalidKeyboardOption {
+                option: "minute_columns",
+                columns: 0,
+                cells: None
+            },
+            KeyboardOptionsBuilder::default()
+                .minute_columns(0)
+                .build()
+                .unwrap_err()
+        );
+    }
+}
@@ -1,9 +1,23 @@
 #![forbid(unsafe_code)]
 
+pub mod calendar_era;
+pub mod calendar_grid;
+pub mod clock;
 pub mod config;
+pub mod date_format;
 pub mod dialog_view_type;
+pub mod fiscal_calendar;
+pub mod keyboard;
+pub mod locale;
+pub mod multi_selection;
+pub mod picker_state;
+pub mod range_selection;
+#[cfg(feature = "thai-holidays")]
+pub mod thai_holidays;
+pub mod time_slots;
 pub mod utils;
 pub mod viewed_date;
+pub mod week_numbering;
 
 #[cfg(test)]
 pub mod rstest_utils;
@@ -0,0 +1,229 @@
+use time::{Month, Weekday};
+
+use crate::calendar_era::CalendarEra;
+
+const THAI_MONTHS: [&str; 12] = [
+    "มกราคม",
+    "กุมภาพันธ์",
+    "มีนาคม",
+    "เมษายน",
+    "พฤษภาคม",
+    "มิถุนายน",
+    "กรกฎาคม",
+    "สิงหาคม",
+    "กันยายน",
+    "ตุลาคม",
+    "พฤศจิกายน",
+    "ธันวาคม",
+];
+
+const THAI_MONTH_ABBREVIATIONS: [&str; 12] = [
+    "ม.ค.",
+    "ก.พ.",
+    "มี.ค.",
+    "เม.ย.",
+    "พ.ค.",
+    "มิ.ย.",
+    "ก.ค.",
+    "ส.ค.",
+    "ก.ย.",
+    "ต.ค.",
+    "พ.ย.",
+    "ธ.ค.",
+];
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const ENGLISH_MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// indexed by `Weekday::number_days_from_sunday`
+const THAI_WEEKDAYS: [&str; 7] = [
+    "วันอาทิตย์",
+    "วันจันทร์",
+    "วันอังคาร",
+    "วันพุธ",
+    "วันพฤหัสบดี",
+    "วันศุกร์",
+    "วันเสาร์",
+];
+
+const THAI_WEEKDAY_ABBREVIATIONS: [&str; 7] = ["อา.", "จ.", "อ.", "พ.", "พฤ.", "ศ.", "ส."];
+
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const ENGLISH_WEEKDAY_ABBREVIATIONS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// First of the Thai digits `๐` to `๙`
+const THAI_DIGIT_ZERO: u32 = '๐' as u32;
+
+/// Language of the names of the months, weekdays and eras.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// Thai names, like "มกราคม" or "ม.ค."
+    #[default]
+    Thai,
+    /// English names, like "January" or "Jan"
+    English,
+}
+
+impl Locale {
+    /// Returns the full name of the given month
+    pub const fn month_name(&self, month: Month) -> &'static str {
+        let index = month as usize - 1;
+        match self {
+            Locale::Thai => THAI_MONTHS[index],
+            Locale::English => ENGLISH_MONTHS[index],
+        }
+    }
+
+    /// Returns the abbreviated name of the given month
+    pub const fn month_abbreviation(&self, month: Month) -> &'static str {
+        let index = month as usize - 1;
+        match self {
+            Locale::Thai => THAI_MONTH_ABBREVIATIONS[index],
+            Locale::English => ENGLISH_MONTH_ABBREVIATIONS[index],
+        }
+    }
+
+    /// Returns the full name of the given weekday
+    pub const fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        let index = weekday.number_days_from_sunday() as usize;
+        match self {
+            Locale::Thai => THAI_WEEKDAYS[index],
+            Locale::English => ENGLISH_WEEKDAYS[index],
+        }
+    }
+
+    /// Returns the abbreviated name of the given weekday
+    pub const fn weekday_abbreviation(&self, weekday: Weekday) -> &'static str {
+        let index = weekday.number_days_from_sunday() as usize;
+        match self {
+            Locale::Thai => THAI_WEEKDAY_ABBREVIATIONS[index],
+            Locale::English => ENGLISH_WEEKDAY_ABBREVIATIONS[index],
+        }
+    }
+
+    /// Returns the name of a fiscal year, like "ปีงบประมาณ" or "Fiscal year"
+    pub const fn fiscal_year_name(&self) -> &'static str {
+        match self {
+            Locale::Thai => "ปีงบประมาณ",
+            Locale::English => "Fiscal year",
+        }
+    }
+
+    /// Returns the abbreviated name of the given era, like "พ.ศ." or "BE"
+    pub const fn era_abbreviation(&self, era: &CalendarEra) -> &'static str {
+        match (self, era) {
+            (Locale::Thai, CalendarEra::Christian) => "ค.ศ.",
+            (Locale::Thai, CalendarEra::Buddhist { .. }) => "พ.ศ.",
+            (Locale::English, CalendarEra::Christian) => "CE",
+            (Locale::English, CalendarEra::Buddhist { .. }) => "BE",
+        }
+    }
+}
+
+/// Returns the given text with the ASCII digits replaced by Thai digits
+pub fn to_thai_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => char::from_u32(THAI_DIGIT_ZERO + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+/// Returns the value of the given ASCII or Thai digit
+pub fn digit_value(c: char) -> Option<u32> {
+    c.to_digit(10).or_else(|| {
+        (c as u32)
+            .checked_sub(THAI_DIGIT_ZERO)
+            .filter(|digit| *digit < 10)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+
+    #[rstest(
+        expected, locale, month, //
+        case::thai_january("มกราคม", Locale::Thai, Month::January),
+        case::thai_december("ธันวาคม", Locale::Thai, Month::December),
+        case::english_july("July", Locale::English, Month::July),
+    )]
+    fn month_name(expected: &str, locale: Locale, month: Month) {
+        assert_eq!(expected, locale.month_name(month));
+    }
+
+    #[rstest(
+        expected, locale, month, //
+        case::thai_january("ม.ค.", Locale::Thai, Month::January),
+        case::thai_april("เม.ย.", Locale::Thai, Month::April),
+        case::english_september("Sep", Locale::English, Month::September),
+    )]
+    fn month_abbreviation(expected: &str, locale: Locale, month: Month) {
+        assert_eq!(expected, locale.month_abbreviation(month));
+    }
+
+    #[rstest(
+        expected, locale, weekday, //
+        case::thai_sunday("วันอาทิตย์", Locale::Thai, Weekday::Sunday),
+        case::thai_thursday("วันพฤหัสบดี", Locale::Thai, Weekday::Thursday),
+        case::english_monday("Monday", Locale::English, Weekday::Monday),
+    )]
+    fn weekday_name(expected: &str, locale: Locale, weekday: Weekday) {
+        assert_eq!(expected, locale.weekday_name(weekday));
+    }
+
+    #[rstest(
+        expected, locale, weekday, //
+        case::thai_wednesday("พ.", Locale::Thai, Weekday::Wednesday),
+        case::thai_thursday("พฤ.", Locale::Thai, Weekday::Thursday),
+        case::english_saturday("Sat", Locale::English, Weekday::Saturday),
+    )]
+    fn weekday_abbreviation(expected: &str, locale: Locale, weekday: Weekday) {
+        assert_eq!(expected, locale.weekday_abbreviation(weekday));
+    }
+
+    #[test]
+    fn to_thai_digits_replaces_only_digits() {
+        assert_eq!("๑๒ ม.ค. ๒๕๖๗", to_thai_digits("12 ม.ค. 2567"));
+    }
+
+    #[rstest(
+        expected, input, //
+        case::ascii(Some(7), '7'),
+        case::thai_zero(Some(0), '๐'),
+        case::thai_nine(Some(9), '๙'),
+        case::letter(None, 'a'),
+        case::thai_letter(None, 'ก'),
+    )]
+    fn test_digit_value(expected: Option<u32>, input: char) {
+        assert_eq!(expected, digit_value(input));
+    }
+}
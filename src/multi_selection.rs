@@ -0,0 +1,405 @@
+use std::collections::BTreeSet;
+
+use derive_getters::Getters;
+use time::{Date, PrimitiveDateTime, Time, Weekday};
+
+use crate::{
+    config::date_constraints::HasDateConstraints, dialog_view_type::DialogViewType,
+    fiscal_calendar::FiscalCalendar, utils::is_forbidden_in_view, viewed_date::ViewedDate,
+};
+
+/// Reason why a value can not be added to a `MultiSelection`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MultiSelectionRejection {
+    /// The year, month or day is forbidden
+    Forbidden,
+    /// `max_count` values are already selected
+    LimitReached,
+}
+
+/// Result of `MultiSelection::toggle`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MultiSelectionEvent {
+    /// The given value was added to the selection
+    Added(Date),
+    /// The given value was removed from the selection
+    Removed(Date),
+    /// The value was not added, the selection did not change
+    Rejected(MultiSelectionRejection),
+}
+
+/// State of the selection of several individual years, half years, quarters, months, weeks or days.
+/// The selection of hours or minutes is not supported, these selection types select whole days.
+#[derive(Debug, Clone, Getters)]
+pub struct MultiSelection {
+    /// Scale of the selected values, to select for example several months
+    selection_type: DialogViewType,
+
+    /// First day of the selected weeks, should be `PickerConfig::first_weekday`
+    first_weekday: Weekday,
+
+    /// Fiscal calendar of the selected years, half years and quarters,
+    /// should be `PickerConfig::fiscal_calendar`
+    fiscal_calendar: FiscalCalendar,
+
+    /// Maximal number of selected values, unlimited if None
+    max_count: Option<usize>,
+
+    /// Selected values in chronological order, values larger than a day are represented by their first day,
+    /// weeks start on `first_weekday`, years, half years and quarters follow `fiscal_calendar`
+    selected: BTreeSet<Date>,
+}
+
+impl Default for MultiSelection {
+    fn default() -> Self {
+        Self::new(
+            DialogViewType::default(),
+            Weekday::Sunday,
+            FiscalCalendar::default(),
+            None,
+        )
+    }
+}
+
+impl MultiSelection {
+    pub fn new(
+        selection_type: DialogViewType,
+        first_weekday: Weekday,
+        fiscal_calendar: FiscalCalendar,
+        max_count: Option<usize>,
+    ) -> Self {
+        Self {
+            selection_type,
+            first_weekday,
+            fiscal_calendar,
+            max_count,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    /// Returns true if the year, month or day including the given datetime is selected
+    pub fn contains(&self, date: &PrimitiveDateTime) -> bool {
+        self.selected.contains(&self.normalize(date))
+    }
+
+    /// Returns true if no more value can be added
+    pub fn is_full(&self) -> bool {
+        self.max_count
+            .is_some_and(|max_count| self.selected.len() >= max_count)
+    }
+
+    /// Removes the year, month or day including the given datetime if it is selected,
+    /// otherwise adds it if it is allowed and the `max_count` is not reached
+    pub fn toggle<T: HasDateConstraints>(
+        &mut self,
+        date: &PrimitiveDateTime,
+        config: &T,
+    ) -> MultiSelectionEvent {
+        let value = self.normalize(date);
+        if self.selected.remove(&value) {
+            return MultiSelectionEvent::Removed(value);
+        }
+        if self.is_forbidden(&value, config) {
+            return MultiSelectionEvent::Rejected(MultiSelectionRejection::Forbidden);
+        }
+        if self.is_full() {
+            return MultiSelectionEvent::Rejected(MultiSelectionRejection::LimitReached);
+        }
+        self.selected.insert(value);
+        MultiSelectionEvent::Added(value)
+    }
+
+    /// Removes the selected values that became forbidden, returns the removed values
+    pub fn remove_forbidden<T: HasDateConstraints>(&mut self, config: &T) -> Vec<Date> {
+        let forbidden: Vec<Date> = self
+            .selected
+            .iter()
+            .filter(|date| self.is_forbidden(date, config))
+            .copied()
+            .collect();
+        for date in &forbidden {
+            self.selected.remove(date);
+        }
+        forbidden
+    }
+
+    /// Clears the selected values
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Returns true if the value represented by the given date is forbidden,
+    /// checked from the start of its first day both when it is added and when it is re-checked
+    fn is_forbidden<T: HasDateConstraints>(&self, value: &Date, config: &T) -> bool {
+        is_forbidden_in_view(
+            config,
+            &self.scale(),
+            &PrimitiveDateTime::new(*value, Time::MIDNIGHT),
+        )
+    }
+
+    /// Returns the view type of the selected values, days for the selection of hours or minutes
+    fn scale(&self) -> DialogViewType {
+        match self.selection_type {
+            DialogViewType::Hours | DialogViewType::Minutes => DialogViewType::Days,
+            selection_type => selection_type,
+        }
+    }
+
+    /// Returns the date representing the year, half year, quarter, month, week or day
+    /// including the given datetime
+    fn normalize(&self, date: &PrimitiveDateTime) -> Date {
+        match self.scale() {
+            DialogViewType::Years => self.fiscal_calendar.first_day_of_year(date).date(),
+            DialogViewType::HalfYears => self.fiscal_calendar.first_day_of_half_year(date).date(),
+            DialogViewType::Quarters => self.fiscal_calendar.first_day_of_quarter(date).date(),
+            DialogViewType::Months => date.first_day_of_month().date(),
+            DialogViewType::Weeks => date.week_start(self.first_weekday).date(),
+            _ => date.date(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        PickerConfigBuilder,
+        date_constraints::{DateConstraints, DateConstraintsBuilder, TimeWindow},
+    };
+
+    use rstest::*;
+    use time::{
+        Month,
+        macros::{date, datetime, time},
+    };
+
+    #[rstest(
+        expected, selection_type, date, //
+        case::years(date!(2024 - 01 - 01), DialogViewType::Years, datetime!(2024-05-31 10:00)),
+        case::half_years(date!(2024 - 01 - 01), DialogViewType::HalfYears, datetime!(2024-05-31 10:00)),
+        case::quarters(date!(2024 - 04 - 01), DialogViewType::Quarters, datetime!(2024-05-31 10:00)),
+        case::months(date!(2024 - 05 - 01), DialogViewType::Months, datetime!(2024-05-31 10:00)),
+        case::weeks(date!(2024 - 05 - 27), DialogViewType::Weeks, datetime!(2024-05-31 10:00)),
+        case::days(date!(2024 - 05 - 31), DialogViewType::Days, datetime!(2024-05-31 10:00)),
+        case::hours(date!(2024 - 05 - 31), DialogViewType::Hours, datetime!(2024-05-31 10:00)),
+    )]
+    fn toggle_adds(expected: Date, selection_type: DialogViewType, date: PrimitiveDateTime) {
+        let mut selection = MultiSelection::new(
+            selection_type,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        assert_eq!(
+            MultiSelectionEvent::Added(expected),
+            selection.toggle(&date, &DateConstraints::default())
+        );
+        assert!(selection.contains(&date));
+    }
+
+    #[rstest(
+        expected, selection_type, date, //
+        case::years(date!(2023 - 10 - 01), DialogViewType::Years, datetime!(2024-05-31 10:00)),
+        case::half_years(date!(2024 - 04 - 01), DialogViewType::HalfYears, datetime!(2024-05-31 10:00)),
+        case::half_years_across_calendar_years(date!(2023 - 10 - 01), DialogViewType::HalfYears, datetime!(2024-02-29 10:00)),
+        case::quarters(date!(2024 - 04 - 01), DialogViewType::Quarters, datetime!(2024-05-31 10:00)),
+        case::quarters_across_calendar_years(date!(2023 - 10 - 01), DialogViewType::Quarters, datetime!(2023-12-31 10:00)),
+    )]
+    fn toggle_adds_fiscal(expected: Date, selection_type: DialogViewType, date: PrimitiveDateTime) {
+        let mut selection = MultiSelection::new(
+            selection_type,
+            Weekday::Monday,
+            FiscalCalendar::THAI_GOVERNMENT,
+            None,
+        );
+        assert_eq!(
+            MultiSelectionEvent::Added(expected),
+            selection.toggle(&date, &DateConstraints::default())
+        );
+        assert!(selection.contains(&PrimitiveDateTime::new(expected, Time::MIDNIGHT)));
+    }
+
+    #[test]
+    fn toggle_fiscal_quarter_of_config() {
+        // the calendar quarter from October to December is forbidden,
+        // the fiscal quarter from November to January is not
+        let config = PickerConfigBuilder::default()
+            .fiscal_calendar(FiscalCalendar::new(Month::November))
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2025-01-01 00:00))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut selection = MultiSelection::new(
+            DialogViewType::Quarters,
+            Weekday::Monday,
+            *config.fiscal_calendar(),
+            None,
+        );
+        assert_eq!(
+            MultiSelectionEvent::Added(date!(2024 - 11 - 01)),
+            selection.toggle(&datetime!(2024-12-15 00:00), &config)
+        );
+        assert!(selection.contains(&datetime!(2025-01-31 00:00)));
+        assert!(!selection.contains(&datetime!(2024-10-31 00:00)));
+    }
+
+    #[test]
+    fn toggle_adds_sunday_first_week() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Weeks,
+            Weekday::Sunday,
+            FiscalCalendar::default(),
+            None,
+        );
+        assert_eq!(
+            MultiSelectionEvent::Added(date!(2024 - 05 - 26)),
+            selection.toggle(&datetime!(2024-05-31 10:00), &DateConstraints::default())
+        );
+        assert!(selection.contains(&datetime!(2024-06-01 10:00)));
+        assert!(!selection.contains(&datetime!(2024-06-02 10:00)));
+    }
+
+    #[test]
+    fn toggle_removes() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Months,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        let config = DateConstraints::default();
+        selection.toggle(&datetime!(2024-05-31 10:00), &config);
+        assert_eq!(
+            MultiSelectionEvent::Removed(date!(2024 - 05 - 01)),
+            selection.toggle(&datetime!(2024-05-01 00:00), &config)
+        );
+        assert!(selection.selected().is_empty());
+    }
+
+    #[test]
+    fn toggle_keeps_chronological_order() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Days,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        let config = DateConstraints::default();
+        for date in [
+            datetime!(2024-05-20 00:00),
+            datetime!(2023-12-01 00:00),
+            datetime!(2024-05-02 00:00),
+        ] {
+            selection.toggle(&date, &config);
+        }
+        assert_eq!(
+            vec![
+                date!(2023 - 12 - 01),
+                date!(2024 - 05 - 02),
+                date!(2024 - 05 - 20)
+            ],
+            selection.selected().iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn toggle_forbidden() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Days,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        let config = DateConstraintsBuilder::default()
+            .disabled_weekdays([Weekday::Saturday].into_iter().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            MultiSelectionEvent::Rejected(MultiSelectionRejection::Forbidden),
+            selection.toggle(&datetime!(2024-05-11 00:00), &config)
+        );
+        assert!(selection.selected().is_empty());
+    }
+
+    #[test]
+    fn toggle_limit_reached() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Days,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            Some(2),
+        );
+        let config = DateConstraints::default();
+        selection.toggle(&datetime!(2024-05-01 00:00), &config);
+        selection.toggle(&datetime!(2024-05-02 00:00), &config);
+        assert!(selection.is_full());
+        assert_eq!(
+            MultiSelectionEvent::Rejected(MultiSelectionRejection::LimitReached),
+            selection.toggle(&datetime!(2024-05-03 00:00), &config)
+        );
+        // removing is still possible
+        assert_eq!(
+            MultiSelectionEvent::Removed(date!(2024 - 05 - 01)),
+            selection.toggle(&datetime!(2024-05-01 00:00), &config)
+        );
+    }
+
+    #[test]
+    fn remove_forbidden() {
+        let mut selection = MultiSelection::new(
+            DialogViewType::Days,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        selection.toggle(&datetime!(2024-05-10 00:00), &DateConstraints::default());
+        selection.toggle(&datetime!(2024-05-11 00:00), &DateConstraints::default());
+        let config = DateConstraintsBuilder::default()
+            .disabled_weekdays([Weekday::Saturday].into_iter().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            vec![date!(2024 - 05 - 11)],
+            selection.remove_forbidden(&config)
+        );
+        assert!(selection.contains(&datetime!(2024-05-10 00:00)));
+    }
+
+    #[rstest(
+        selection_type, //
+        case::days(DialogViewType::Days),
+        case::hours(DialogViewType::Hours),
+        case::weeks(DialogViewType::Weeks),
+        case::months(DialogViewType::Months),
+    )]
+    fn remove_forbidden_keeps_values_added_in_time_window(selection_type: DialogViewType) {
+        let config = DateConstraintsBuilder::default()
+            .allowed_time_windows(
+                [(
+                    Weekday::Friday,
+                    vec![TimeWindow::new(time!(09:00), time!(12:00))],
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        let mut selection = MultiSelection::new(
+            selection_type,
+            Weekday::Monday,
+            FiscalCalendar::default(),
+            None,
+        );
+        assert!(matches!(
+            selection.toggle(&datetime!(2024-05-10 09:00), &config),
+            MultiSelectionEvent::Added(_)
+        ));
+        assert_eq!(Vec::<Date>::new(), selection.remove_forbidden(&config));
+        assert!(selection.contains(&datetime!(2024-05-10 09:00)));
+    }
+}
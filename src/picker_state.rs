@@ -0,0 +1,838 @@
+use derive_getters::Getters;
+use time::PrimitiveDateTime;
+
+use crate::{
+    clock::{Clock, DefaultClock},
+    config::{PickerConfig, date_constraints::HasDateConstraints},
+    dialog_view_type::DialogViewType,
+    keyboard::{KeyboardOptions, NavigationKey, move_focus, normalize},
+    time_slots::floor_to_time_slot,
+    utils::{
+        is_forbidden_in_view, should_display_next_button_in, should_display_previous_button_in,
+    },
+    viewed_date::{ViewedDate, year_group_start},
+};
+
+/// Change of a `DatePickerState`, returned by the methods changing the state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PickerEvent {
+    /// The viewed date changed to the given datetime
+    ViewedDateChanged(PrimitiveDateTime),
+    /// The view type changed to the given view type
+    ViewTypeChanged(DialogViewType),
+    /// The given datetime was selected at the scale of the `selection_type`
+    Selected(PrimitiveDateTime),
+    /// The focused cell changed to the given datetime by `focus` or `press_key`
+    FocusChanged(PrimitiveDateTime),
+}
+
+/// Framework agnostic state of a datepicker dialog.
+#[derive(Debug, Clone, Getters)]
+pub struct DatePickerState<T: HasDateConstraints + Default + Clone> {
+    /// Configuration of the datepicker
+    config: PickerConfig<T>,
+
+    /// Currently viewed date, its year group, year, month, day or hour is displayed depending on the `view_type`
+    viewed_date: PrimitiveDateTime,
+
+    /// Currently displayed view type
+    view_type: DialogViewType,
+
+    /// Currently selected value
+    selected: Option<PrimitiveDateTime>,
+
+    /// Cell focused by the keyboard navigation, initially the viewed date.
+    /// It moves to the viewed date when it leaves the viewed page by a change of
+    /// the viewed date or of the view type, without a `PickerEvent::FocusChanged`
+    focused: PrimitiveDateTime,
+}
+
+impl<T> DatePickerState<T>
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    /// Creates the state of a datepicker opened on `PickerConfig::guess_allowed_year_month`,
+    /// or on the current datetime if every day is forbidden
+    pub fn new(config: PickerConfig<T>) -> Self {
+        Self::new_with(config, &DefaultClock::default())
+    }
+
+    /// Same as `new`, with the current datetime given by the clock
+    pub fn new_with<C: Clock>(config: PickerConfig<T>, clock: &C) -> Self {
+        let viewed_date = config
+            .guess_allowed_year_month_with(clock)
+            .unwrap_or_else(|| config.now(clock));
+        Self {
+            viewed_date,
+            view_type: *config.initial_view_type(),
+            selected: *config.initial_date(),
+            focused: normalize(&config, config.initial_view_type(), &viewed_date),
+            config,
+        }
+    }
+
+    /// Returns true if the previous year group, year, month, day or hour can be viewed
+    pub fn has_previous(&self) -> bool {
+        if self.is_year_view() {
+            let fiscal_calendar = self.config.fiscal_calendar();
+            return !self
+                .config
+                .is_year_forbidden(&fiscal_calendar.previous_year(&self.viewed_date));
+        }
+        if self.view_type == DialogViewType::Years {
+            let previous = self
+                .config
+                .fiscal_calendar()
+                .previous_year_group(self.config.calendar_era(), &self.viewed_date);
+            return previous != self.viewed_date && !self.config.is_year_group_forbidden(&previous);
+        }
+        should_display_previous_button_in(
+            self.config.calendar_era(),
+            &self.view_type,
+            &self.viewed_date,
+            &self.config,
+        )
+    }
+
+    /// Returns true if the next year group, year, month, day or hour can be viewed
+    pub fn has_next(&self) -> bool {
+        if self.is_year_view() {
+            let fiscal_calendar = self.config.fiscal_calendar();
+            return !self
+                .config
+                .is_year_forbidden(&fiscal_calendar.next_year(&self.viewed_date));
+        }
+        if self.view_type == DialogViewType::Years {
+            let next = self
+                .config
+                .fiscal_calendar()
+                .next_year_group(self.config.calendar_era(), &self.viewed_date);
+            return next != self.viewed_date && !self.config.is_year_group_forbidden(&next);
+        }
+        should_display_next_button_in(
+            self.config.calendar_era(),
+            &self.view_type,
+            &self.viewed_date,
+            &self.config,
+        )
+    }
+
+    /// Views the previous year group, year, month, day or hour depending on the `view_type`,
+    /// if it is not entirely forbidden
+    pub fn previous(&mut self) -> Vec<PickerEvent> {
+        if !self.has_previous() {
+            return Vec::new();
+        }
+        let viewed_date = match self.view_type {
+            DialogViewType::Years => self
+                .config
+                .fiscal_calendar()
+                .previous_year_group(self.config.calendar_era(), &self.viewed_date),
+            DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => self
+                .config
+                .fiscal_calendar()
+                .previous_year(&self.viewed_date),
+            DialogViewType::Weeks | DialogViewType::Days => self.viewed_date.previous_month(),
+            DialogViewType::Hours => self.viewed_date.previous_day(),
+            DialogViewType::Minutes => self.viewed_date.previous_hour(),
+        };
+        self.view(viewed_date)
+    }
+
+    /// Views the next year group, year, month, day or hour depending on the `view_type`,
+    /// if it is not entirely forbidden
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Vec<PickerEvent> {
+        if !self.has_next() {
+            return Vec::new();
+        }
+        let viewed_date = match self.view_type {
+            DialogViewType::Years => self
+                .config
+                .fiscal_calendar()
+                .next_year_group(self.config.calendar_era(), &self.viewed_date),
+            DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+                self.config.fiscal_calendar().next_year(&self.viewed_date)
+            }
+            DialogViewType::Weeks | DialogViewType::Days => self.viewed_date.next_month(),
+            DialogViewType::Hours => self.viewed_date.next_day(),
+            DialogViewType::Minutes => self.viewed_date.next_hour(),
+        };
+        self.view(viewed_date)
+    }
+
+    /// Switches to the larger view type, if such exists
+    pub fn zoom_out(&mut self) -> Vec<PickerEvent> {
+        match self.view_type.larger_type() {
+            Some(view_type) => {
+                self.view_type = view_type;
+                self.anchor_focus();
+                vec![PickerEvent::ViewTypeChanged(view_type)]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Selects the year, month, week or day including the given datetime depending on the `view_type`.
+    /// Switches to the smaller view type of the selected value if the `view_type` is larger than
+    /// the `selection_type`, otherwise the value becomes the `selected` one.
+    /// A week is selected as its first day, starting on `PickerConfig::first_weekday`,
+    /// and a minute is rounded down to a step of `PickerConfig::minute_step`.
+    /// Nothing changes if the value is forbidden.
+    pub fn select(&mut self, date: PrimitiveDateTime) -> Vec<PickerEvent> {
+        let value = match self.view_type {
+            DialogViewType::Weeks => date.week_start(*self.config.first_weekday()),
+            DialogViewType::Minutes => floor_to_time_slot(&date, *self.config.minute_step()),
+            _ => date,
+        };
+        if is_forbidden_in_view(&self.config, &self.view_type, &value) {
+            return Vec::new();
+        }
+        match self.view_type.smaller_type(self.config.selection_type()) {
+            Some(view_type) => {
+                let mut events = self.view(date);
+                self.view_type = view_type;
+                self.focused = date;
+                self.anchor_focus();
+                events.push(PickerEvent::ViewTypeChanged(view_type));
+                events
+            }
+            None => {
+                let mut events = if self.is_viewed(&date) {
+                    Vec::new()
+                } else {
+                    self.view(date)
+                };
+                self.selected = Some(value);
+                self.focused = value;
+                events.push(PickerEvent::Selected(value));
+                events
+            }
+        }
+    }
+
+    /// Focuses the cell including the given datetime, viewing its page if it is not displayed.
+    /// A week is focused as its first day and a minute is rounded down to a step of `PickerConfig::minute_step`.
+    pub fn focus(&mut self, date: PrimitiveDateTime) -> Vec<PickerEvent> {
+        let date = normalize(&self.config, &self.view_type, &date);
+        let previous_focus = self.focused;
+        let mut events = if self.is_viewed(&date) {
+            Vec::new()
+        } else {
+            self.view(date)
+        };
+        self.focused = date;
+        if date != previous_focus {
+            events.push(PickerEvent::FocusChanged(date));
+        }
+        events
+    }
+
+    /// Moves the focused cell of the `view_type` grid according to the given key,
+    /// nothing changes if no allowed cell can be focused
+    pub fn press_key(&mut self, key: NavigationKey, options: &KeyboardOptions) -> Vec<PickerEvent> {
+        match move_focus(&self.config, options, &self.view_type, &self.focused, key) {
+            Some(date) => self.focus(date),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns true if the `view_type` displays a single year
+    fn is_year_view(&self) -> bool {
+        matches!(
+            self.view_type,
+            DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months
+        )
+    }
+
+    /// Returns true if the given datetime is displayed in the current year group, year, month, day or hour
+    fn is_viewed(&self, date: &PrimitiveDateTime) -> bool {
+        let era = self.config.calendar_era();
+        let fiscal_calendar = self.config.fiscal_calendar();
+        match self.view_type.larger_type() {
+            Some(DialogViewType::Years) if !fiscal_calendar.is_calendar_year() => {
+                fiscal_calendar.fiscal_year(&self.viewed_date.date())
+                    == fiscal_calendar.fiscal_year(&date.date())
+            }
+            Some(larger_type) => {
+                self.viewed_date
+                    .contains_in(era, *self.config.first_weekday(), &larger_type, date)
+            }
+            None => {
+                year_group_start(fiscal_calendar.year_label(&self.viewed_date.date(), era))
+                    == year_group_start(fiscal_calendar.year_label(&date.date(), era))
+            }
+        }
+    }
+
+    /// Moves the focus to the viewed date if it is not displayed,
+    /// then to the cell of the `view_type` including it
+    fn anchor_focus(&mut self) {
+        if !self.is_viewed(&self.focused) {
+            self.focused = self.viewed_date;
+        }
+        self.focused = normalize(&self.config, &self.view_type, &self.focused);
+    }
+
+    fn view(&mut self, viewed_date: PrimitiveDateTime) -> Vec<PickerEvent> {
+        if viewed_date == self.viewed_date {
+            return Vec::new();
+        }
+        self.viewed_date = viewed_date;
+        self.anchor_focus();
+        vec![PickerEvent::ViewedDateChanged(viewed_date)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calendar_era::CalendarEra,
+        clock::FixedClock,
+        config::{
+            PickerConfigBuilder,
+            date_constraints::{DateConstraints, DateConstraintsBuilder},
+        },
+        fiscal_calendar::FiscalCalendar,
+    };
+
+    use rstest::*;
+    use time::{Month, macros::datetime};
+
+    fn create_state(
+        initial_view_type: DialogViewType,
+        selection_type: DialogViewType,
+        date_constraints: DateConstraints,
+    ) -> DatePickerState<DateConstraints> {
+        let config = PickerConfigBuilder::default()
+            .initial_view_type(initial_view_type)
+            .selection_type(selection_type)
+            .date_constraints(date_constraints)
+            .build()
+            .unwrap();
+        DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)))
+    }
+
+    #[test]
+    fn new_opens_on_allowed_day() {
+        let state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraintsBuilder::default()
+                .min_datetime(datetime!(2024-06-01 00:00))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(datetime!(2024-06-01 10:00), *state.viewed_date());
+        assert_eq!(DialogViewType::Days, *state.view_type());
+        assert_eq!(None, *state.selected());
+    }
+
+    #[test]
+    fn new_selects_initial_date() {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .initial_date(datetime!(2020-02-02 00:00))
+            .build()
+            .unwrap();
+        let state = DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        assert_eq!(datetime!(2020-02-02 00:00), *state.viewed_date());
+        assert_eq!(Some(datetime!(2020-02-02 00:00)), *state.selected());
+    }
+
+    #[rstest(
+        expected, view_type, //
+        case::years(datetime!(2019-01-01 10:00), DialogViewType::Years),
+        case::months(datetime!(2023-01-01 10:00), DialogViewType::Months),
+        case::days(datetime!(2024-04-01 10:00), DialogViewType::Days),
+    )]
+    fn previous(expected: PrimitiveDateTime, view_type: DialogViewType) {
+        let mut state = create_state(view_type, DialogViewType::Days, DateConstraints::default());
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(expected)],
+            state.previous()
+        );
+        assert_eq!(expected, *state.viewed_date());
+    }
+
+    #[rstest(
+        expected, view_type, //
+        case::years(datetime!(2040-01-01 10:00), DialogViewType::Years),
+        case::months(datetime!(2025-01-01 10:00), DialogViewType::Months),
+        case::days(datetime!(2024-06-01 10:00), DialogViewType::Days),
+    )]
+    fn next(expected: PrimitiveDateTime, view_type: DialogViewType) {
+        let mut state = create_state(view_type, DialogViewType::Days, DateConstraints::default());
+        assert_eq!(vec![PickerEvent::ViewedDateChanged(expected)], state.next());
+        assert_eq!(expected, *state.viewed_date());
+    }
+
+    #[test]
+    fn previous_year_group_in_buddhist_era() {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .initial_view_type(DialogViewType::Years)
+            .calendar_era(CalendarEra::Buddhist {
+                april_new_year: false,
+            })
+            .build()
+            .unwrap();
+        let mut state =
+            DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2016-01-01 10:00))],
+            state.previous()
+        );
+    }
+
+    #[test]
+    fn previous_forbidden_month() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraintsBuilder::default()
+                .min_datetime(datetime!(2024-05-01 00:00))
+                .build()
+                .unwrap(),
+        );
+        assert!(!state.has_previous());
+        assert_eq!(Vec::<PickerEvent>::new(), state.previous());
+        assert_eq!(datetime!(2024-05-15 10:00), *state.viewed_date());
+    }
+
+    #[test]
+    fn zoom_out_to_years() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![PickerEvent::ViewTypeChanged(DialogViewType::Months)],
+            state.zoom_out()
+        );
+        assert_eq!(
+            vec![PickerEvent::ViewTypeChanged(DialogViewType::Years)],
+            state.zoom_out()
+        );
+        assert_eq!(Vec::<PickerEvent>::new(), state.zoom_out());
+        assert_eq!(DialogViewType::Years, *state.view_type());
+    }
+
+    #[test]
+    fn select_zooms_in_until_selection_type() {
+        let mut state = create_state(
+            DialogViewType::Years,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2030-01-01 00:00)),
+                PickerEvent::ViewTypeChanged(DialogViewType::Months)
+            ],
+            state.select(datetime!(2030-01-01 00:00))
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2030-03-01 00:00)),
+                PickerEvent::ViewTypeChanged(DialogViewType::Days)
+            ],
+            state.select(datetime!(2030-03-01 00:00))
+        );
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2030-03-14 00:00))],
+            state.select(datetime!(2030-03-14 00:00))
+        );
+        assert_eq!(Some(datetime!(2030-03-14 00:00)), *state.selected());
+    }
+
+    #[test]
+    fn select_at_month_selection_type() {
+        let mut state = create_state(
+            DialogViewType::Months,
+            DialogViewType::Months,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2024-09-01 00:00))],
+            state.select(datetime!(2024-09-01 00:00))
+        );
+        assert_eq!(DialogViewType::Months, *state.view_type());
+    }
+
+    #[test]
+    fn select_outside_viewed_month() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-06-01 00:00)),
+                PickerEvent::Selected(datetime!(2024-06-01 00:00))
+            ],
+            state.select(datetime!(2024-06-01 00:00))
+        );
+    }
+
+    #[test]
+    fn select_week() {
+        let mut state = create_state(
+            DialogViewType::Months,
+            DialogViewType::Weeks,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-05-01 00:00)),
+                PickerEvent::ViewTypeChanged(DialogViewType::Weeks)
+            ],
+            state.select(datetime!(2024-05-01 00:00))
+        );
+        // the week starts on Sunday in the previous month, the viewed month does not change
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2024-04-28 00:00))],
+            state.select(datetime!(2024-05-01 00:00))
+        );
+        assert_eq!(DialogViewType::Weeks, *state.view_type());
+    }
+
+    #[rstest(
+        expected, selection_type, //
+        case::half_years(datetime!(2024-07-01 00:00), DialogViewType::HalfYears),
+        case::quarters(datetime!(2024-08-01 00:00), DialogViewType::Quarters),
+    )]
+    fn select_quarter_or_half_year(expected: PrimitiveDateTime, selection_type: DialogViewType) {
+        let mut state = create_state(
+            DialogViewType::Years,
+            selection_type,
+            DateConstraints::default(),
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-01-01 00:00)),
+                PickerEvent::ViewTypeChanged(selection_type)
+            ],
+            state.select(datetime!(2024-01-01 00:00))
+        );
+        assert_eq!(
+            vec![PickerEvent::Selected(expected)],
+            state.select(expected)
+        );
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2023-01-01 00:00))],
+            state.previous()
+        );
+    }
+
+    #[test]
+    fn fiscal_year_navigation() {
+        let config = PickerConfigBuilder::default()
+            .initial_view_type(DialogViewType::Months)
+            .selection_type(DialogViewType::Months)
+            .fiscal_calendar(FiscalCalendar::THAI_GOVERNMENT)
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2023-10-01 00:00))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut state =
+            DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        // the fiscal year 2024 is from October 2023 to September 2024
+        assert!(!state.has_previous());
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2024-10-01 10:00))],
+            state.next()
+        );
+        assert!(state.has_previous());
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2023-10-01 10:00))],
+            state.previous()
+        );
+    }
+
+    #[test]
+    fn select_in_viewed_fiscal_year() {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .initial_view_type(DialogViewType::Months)
+            .selection_type(DialogViewType::Months)
+            .fiscal_calendar(FiscalCalendar::THAI_GOVERNMENT)
+            .build()
+            .unwrap();
+        let mut state =
+            DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2023-11-01 00:00))],
+            state.select(datetime!(2023-11-01 00:00))
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-10-01 00:00)),
+                PickerEvent::Selected(datetime!(2024-10-01 00:00))
+            ],
+            state.select(datetime!(2024-10-01 00:00))
+        );
+    }
+
+    #[rstest(
+        expected_events, view_type, date, //
+        case::years_same_group(vec![PickerEvent::Selected(datetime!(2039-09-01 00:00))], DialogViewType::Years, datetime!(2039-09-01 00:00)),
+        case::years_next_group(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2039-10-01 00:00)),
+                PickerEvent::Selected(datetime!(2039-10-01 00:00)),
+            ],
+            DialogViewType::Years,
+            datetime!(2039-10-01 00:00)
+        ),
+        case::quarters_same_year(vec![PickerEvent::Selected(datetime!(2024-07-01 00:00))], DialogViewType::Quarters, datetime!(2024-07-01 00:00)),
+        case::quarters_next_year(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-10-01 00:00)),
+                PickerEvent::Selected(datetime!(2024-10-01 00:00)),
+            ],
+            DialogViewType::Quarters,
+            datetime!(2024-10-01 00:00)
+        ),
+    )]
+    fn select_in_fiscal_years(
+        expected_events: Vec<PickerEvent>,
+        view_type: DialogViewType,
+        date: PrimitiveDateTime,
+    ) {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .initial_view_type(view_type)
+            .selection_type(view_type)
+            .fiscal_calendar(FiscalCalendar::new(Month::October))
+            .build()
+            .unwrap();
+        let mut state =
+            DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        assert_eq!(expected_events, state.select(date));
+    }
+
+    #[test]
+    fn fiscal_year_group_navigation() {
+        let config = PickerConfigBuilder::default()
+            .initial_view_type(DialogViewType::Years)
+            .selection_type(DialogViewType::Years)
+            .fiscal_calendar(FiscalCalendar::new(Month::October))
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .max_datetime(datetime!(2039-12-31 00:00))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let state = DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        // the fiscal year 2040 starts on 1 October 2039, it is partly allowed
+        assert!(state.has_next());
+    }
+
+    #[test]
+    fn fiscal_year_group_previous_and_next() {
+        let config = PickerConfigBuilder::<DateConstraints>::default()
+            .initial_view_type(DialogViewType::Years)
+            .selection_type(DialogViewType::Years)
+            .fiscal_calendar(FiscalCalendar::new(Month::October))
+            .initial_date(datetime!(2039-10-15 00:00))
+            .build()
+            .unwrap();
+        let create_state = || {
+            DatePickerState::new_with(config.clone(), &FixedClock(datetime!(2024-05-15 03:00 UTC)))
+        };
+
+        // 15 October 2039 is in the fiscal year 2040, the first of the group 2040-2059
+        let mut state = create_state();
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2059-10-01 00:00))],
+            state.next()
+        );
+        let mut state = create_state();
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2038-10-01 00:00))],
+            state.previous()
+        );
+        assert!(!state.is_viewed(&datetime!(2039-10-01 00:00)));
+        assert!(state.is_viewed(&datetime!(2019-10-01 00:00)));
+    }
+
+    #[test]
+    fn select_time() {
+        let config = PickerConfigBuilder::default()
+            .initial_view_type(DialogViewType::Days)
+            .selection_type(DialogViewType::Minutes)
+            .minute_step(15)
+            .date_constraints(
+                DateConstraintsBuilder::default()
+                    .min_datetime(datetime!(2024-05-15 09:30))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut state =
+            DatePickerState::new_with(config, &FixedClock(datetime!(2024-05-15 03:00 UTC)));
+        assert_eq!(
+            Vec::<PickerEvent>::new(),
+            state.select(datetime!(2024-05-14 10:00))
+        );
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-05-15 00:00)),
+                PickerEvent::ViewTypeChanged(DialogViewType::Hours)
+            ],
+            state.select(datetime!(2024-05-15 00:00))
+        );
+        assert_eq!(
+            Vec::<PickerEvent>::new(),
+            state.select(datetime!(2024-05-15 08:00))
+        );
+        assert!(!state.has_previous());
+        assert_eq!(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-05-15 09:00)),
+                PickerEvent::ViewTypeChanged(DialogViewType::Minutes)
+            ],
+            state.select(datetime!(2024-05-15 09:00))
+        );
+        assert_eq!(
+            Vec::<PickerEvent>::new(),
+            state.select(datetime!(2024-05-15 09:15))
+        );
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2024-05-15 09:45))],
+            state.select(datetime!(2024-05-15 09:52))
+        );
+        assert_eq!(
+            vec![PickerEvent::ViewedDateChanged(datetime!(2024-05-15 10:00))],
+            state.next()
+        );
+    }
+
+    #[test]
+    fn select_forbidden_week() {
+        let mut state = create_state(
+            DialogViewType::Weeks,
+            DialogViewType::Weeks,
+            DateConstraintsBuilder::default()
+                .max_datetime(datetime!(2024-05-25 23:59))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            vec![PickerEvent::Selected(datetime!(2024-05-19 00:00))],
+            state.select(datetime!(2024-05-25 00:00))
+        );
+        assert_eq!(
+            Vec::<PickerEvent>::new(),
+            state.select(datetime!(2024-05-26 00:00))
+        );
+    }
+
+    #[rstest(
+        view_type, date, //
+        case::years(DialogViewType::Years, datetime!(2025-01-01 00:00)),
+        case::months(DialogViewType::Months, datetime!(2024-07-01 00:00)),
+        case::days(DialogViewType::Days, datetime!(2024-07-04 00:00)),
+    )]
+    fn select_forbidden(view_type: DialogViewType, date: PrimitiveDateTime) {
+        let mut state = create_state(
+            view_type,
+            DialogViewType::Days,
+            DateConstraintsBuilder::default()
+                .disabled_years([2025].into_iter().collect())
+                .disabled_months([Month::July].into_iter().collect())
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(Vec::<PickerEvent>::new(), state.select(date));
+        assert_eq!(view_type, *state.view_type());
+        assert_eq!(None, *state.selected());
+    }
+
+    #[rstest(
+        expected, key, //
+        case::same_page(vec![PickerEvent::FocusChanged(datetime!(2024-05-16 10:00))], NavigationKey::Right),
+        case::next_page(
+            vec![
+                PickerEvent::ViewedDateChanged(datetime!(2024-06-15 10:00)),
+                PickerEvent::FocusChanged(datetime!(2024-06-15 10:00)),
+            ],
+            NavigationKey::PageDown
+        ),
+    )]
+    fn press_key(expected: Vec<PickerEvent>, key: NavigationKey) {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        assert_eq!(datetime!(2024-05-15 10:00), *state.focused());
+        assert_eq!(expected, state.press_key(key, &KeyboardOptions::default()));
+    }
+
+    #[test]
+    fn press_key_after_next() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        state.next();
+        assert_eq!(datetime!(2024-06-01 10:00), *state.focused());
+        assert_eq!(
+            vec![PickerEvent::FocusChanged(datetime!(2024-06-02 10:00))],
+            state.press_key(NavigationKey::Right, &KeyboardOptions::default())
+        );
+        assert_eq!(datetime!(2024-06-01 10:00), *state.viewed_date());
+    }
+
+    #[test]
+    fn focus_normalizes_week() {
+        let mut state = create_state(
+            DialogViewType::Weeks,
+            DialogViewType::Weeks,
+            DateConstraints::default(),
+        );
+        assert_eq!(datetime!(2024-05-12 10:00), *state.focused());
+        assert_eq!(
+            vec![PickerEvent::FocusChanged(datetime!(2024-05-19 10:00))],
+            state.focus(datetime!(2024-05-22 10:00))
+        );
+    }
+
+    #[test]
+    fn zoom_out_keeps_focus_in_page() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraints::default(),
+        );
+        state.press_key(NavigationKey::Right, &KeyboardOptions::default());
+        state.zoom_out();
+        assert_eq!(datetime!(2024-05-16 10:00), *state.focused());
+    }
+
+    #[test]
+    fn press_key_forbidden() {
+        let mut state = create_state(
+            DialogViewType::Days,
+            DialogViewType::Days,
+            DateConstraintsBuilder::default()
+                .max_datetime(datetime!(2024-05-15 23:59))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            Vec::<PickerEvent>::new(),
+            state.press_key(NavigationKey::Right, &KeyboardOptions::default())
+        );
+        assert_eq!(datetime!(2024-05-15 10:00), *state.focused());
+    }
+}
@@ -0,0 +1,514 @@
+use std::cell::Cell;
+
+use derive_builder::Builder;
+use derive_getters::Getters;
+use time::{Date, PrimitiveDateTime};
+
+use crate::config::{date_constraints::HasDateConstraints, error::ConfigError};
+
+/// Options of a `RangeSelection`.
+#[derive(Clone, Debug, Default, Builder, Getters)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+#[builder(build_fn(validate = "Self::validate", error = "ConfigError"))]
+pub struct RangeOptions {
+    /// Minimal number of days of a range, including its start and end days
+    min_days: Option<u32>,
+
+    /// Maximal number of days of a range, including its start and end days
+    max_days: Option<u32>,
+
+    /// If true, a range including a forbidden day is rejected
+    reject_forbidden_days: bool,
+}
+
+impl RangeOptionsBuilder {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if let (Some(Some(min_days)), Some(Some(max_days))) = (self.min_days, self.max_days)
+            && min_days > max_days
+        {
+            return Err(ConfigError::MinDaysAfterMaxDays { min_days, max_days });
+        }
+        Ok(())
+    }
+}
+
+/// Reason why a day can not be selected in a `RangeSelection`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RangeRejection {
+    /// The day itself is forbidden
+    ForbiddenDay,
+    /// The range would have less than `min_days` days
+    TooShort,
+    /// The range would have more than `max_days` days
+    TooLong,
+    /// The range would include a forbidden day, with `reject_forbidden_days`
+    IncludesForbiddenDay,
+}
+
+/// Result of `RangeSelection::select`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RangeSelectionEvent {
+    /// A new range was started on the given day
+    Started(PrimitiveDateTime),
+    /// The range was completed, from its start to its end day
+    Completed(PrimitiveDateTime, PrimitiveDateTime),
+    /// The day was not selected, the selection did not change
+    Rejected(RangeRejection),
+}
+
+/// Flags of a day cell in a `RangeSelection`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct RangeCellFlags {
+    /// The day is the first day of the selected or previewed range
+    pub is_range_start: bool,
+    /// The day is in the selected or previewed range, including its start and end days
+    pub is_in_range: bool,
+    /// The day is the last day of the selected or previewed range
+    pub is_range_end: bool,
+    /// Selecting the day would be accepted
+    pub is_selectable: bool,
+}
+
+/// State of the selection of a range of days, from a start day to an end day.
+/// The first selected day starts a range, the second one completes it,
+/// while only the start is selected the hovered day previews the end of the range.
+#[derive(Debug, Clone, Default, Getters)]
+pub struct RangeSelection {
+    /// Options of the selection
+    options: RangeOptions,
+
+    /// First day of the range
+    start: Option<PrimitiveDateTime>,
+
+    /// Last day of the range, only set if the range is completed
+    end: Option<PrimitiveDateTime>,
+
+    /// Currently hovered day
+    hovered: Option<PrimitiveDateTime>,
+
+    /// Days around the first day of the last checked range already scanned for a forbidden day
+    #[getter(skip)]
+    scan: Cell<Option<ForbiddenScan>>,
+}
+
+/// Days scanned for a forbidden day from the first day of a range, on both sides.
+#[derive(Debug, Copy, Clone)]
+struct ForbiddenScan {
+    anchor: Date,
+    before: ScannedSide,
+    after: ScannedSide,
+}
+
+/// Farthest day scanned on one side of the first day of a range,
+/// the scan stops at the nearest forbidden day.
+#[derive(Debug, Copy, Clone)]
+struct ScannedSide {
+    reached: Date,
+    forbidden: bool,
+}
+
+impl RangeSelection {
+    pub fn new(options: RangeOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if both the start and the end of the range are selected
+    pub fn is_complete(&self) -> bool {
+        self.start.is_some() && self.end.is_some()
+    }
+
+    /// Selects the given day as the start of a new range,
+    /// or as the other end of the started range, the earlier day becoming the start
+    pub fn select<T: HasDateConstraints>(
+        &mut self,
+        date: PrimitiveDateTime,
+        config: &T,
+    ) -> RangeSelectionEvent {
+        match self.start.filter(|_| self.end.is_none()) {
+            Some(start) => {
+                if let Some(rejection) = self.check(&start, &date, config) {
+                    return RangeSelectionEvent::Rejected(rejection);
+                }
+                let (start, end) = ordered(start, date);
+                self.start = Some(start);
+                self.end = Some(end);
+                RangeSelectionEvent::Completed(start, end)
+            }
+            None => {
+                if config.is_day_forbidden(&date) {
+                    return RangeSelectionEvent::Rejected(RangeRejection::ForbiddenDay);
+                }
+                self.start = Some(date);
+                self.end = None;
+                self.scan.set(None);
+                RangeSelectionEvent::Started(date)
+            }
+        }
+    }
+
+    /// Sets the currently hovered day, None if no day is hovered
+    pub fn hover(&mut self, date: Option<PrimitiveDateTime>) {
+        self.hovered = date;
+    }
+
+    /// Clears the selected range
+    pub fn clear(&mut self) {
+        self.start = None;
+        self.end = None;
+        self.scan.set(None);
+    }
+
+    /// Returns the selected range if it is complete, otherwise the range from the start
+    /// to the hovered day, as first and last days
+    pub fn preview(&self) -> Option<(Date, Date)> {
+        let start = self.start?;
+        let end = self.end.or(self.hovered).unwrap_or(start);
+        let (start, end) = ordered(start.date(), end.date());
+        Some((start, end))
+    }
+
+    /// Returns the reason why the range between the given days would be rejected, if any.
+    /// The days between are scanned once for the given first day, until `select` or `clear`,
+    /// so checking every cell of a grid does not scan the same days again.
+    pub fn check<T: HasDateConstraints>(
+        &self,
+        first: &PrimitiveDateTime,
+        second: &PrimitiveDateTime,
+        config: &T,
+    ) -> Option<RangeRejection> {
+        if config.is_day_forbidden(second) {
+            return Some(RangeRejection::ForbiddenDay);
+        }
+        let (start, end) = ordered(*first, *second);
+        let days = (end.date() - start.date()).whole_days() + 1;
+        if self.options.min_days.is_some_and(|min| days < min.into()) {
+            return Some(RangeRejection::TooShort);
+        }
+        if self.options.max_days.is_some_and(|max| days > max.into()) {
+            return Some(RangeRejection::TooLong);
+        }
+        if self.options.reject_forbidden_days && self.includes_forbidden_day(first, second, config)
+        {
+            return Some(RangeRejection::IncludesForbiddenDay);
+        }
+        None
+    }
+
+    /// Returns true if a day strictly between the given days is forbidden,
+    /// extending the cached scan from the first day only as far as needed
+    fn includes_forbidden_day<T: HasDateConstraints>(
+        &self,
+        first: &PrimitiveDateTime,
+        second: &PrimitiveDateTime,
+        config: &T,
+    ) -> bool {
+        let (anchor, target) = (first.date(), second.date());
+        let untouched = ScannedSide {
+            reached: anchor,
+            forbidden: false,
+        };
+        let mut scan = self
+            .scan
+            .get()
+            .filter(|scan| scan.anchor == anchor)
+            .unwrap_or(ForbiddenScan {
+                anchor,
+                before: untouched,
+                after: untouched,
+            });
+        let forward = target > anchor;
+        let side = if forward {
+            &mut scan.after
+        } else {
+            &mut scan.before
+        };
+        let is_between = |day: &Date| {
+            if forward {
+                *day < target
+            } else {
+                *day > target
+            }
+        };
+        while !side.forbidden {
+            // the days out of the range of `Date` are never between the given days
+            let next = if forward {
+                side.reached.next_day()
+            } else {
+                side.reached.previous_day()
+            };
+            let Some(day) = next.filter(is_between) else {
+                break;
+            };
+            side.reached = day;
+            side.forbidden = config.is_day_forbidden(&PrimitiveDateTime::new(day, first.time()));
+        }
+        let includes = side.forbidden && is_between(&side.reached);
+        self.scan.set(Some(scan));
+        includes
+    }
+
+    /// Returns the flags of the day cell of the given datetime
+    pub fn cell_flags<T: HasDateConstraints>(
+        &self,
+        date: &PrimitiveDateTime,
+        config: &T,
+    ) -> RangeCellFlags {
+        let day = date.date();
+        let is_selectable = match self.start.filter(|_| self.end.is_none()) {
+            Some(start) => self.check(&start, date, config).is_none(),
+            None => !config.is_day_forbidden(date),
+        };
+        match self.preview() {
+            Some((start, end)) => RangeCellFlags {
+                is_range_start: day == start,
+                is_in_range: start <= day && day <= end,
+                is_range_end: day == end,
+                is_selectable,
+            },
+            None => RangeCellFlags {
+                is_selectable,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+fn ordered<T: Ord>(first: T, second: T) -> (T, T) {
+    if first <= second {
+        (first, second)
+    } else {
+        (second, first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::date_constraints::{DateConstraints, DateConstraintsBuilder};
+
+    use rstest::*;
+    use time::{
+        Weekday,
+        macros::{date, datetime},
+    };
+
+    fn create_selection(
+        min_days: Option<u32>,
+        max_days: Option<u32>,
+        reject_forbidden_days: bool,
+    ) -> RangeSelection {
+        let mut options = RangeOptionsBuilder::default();
+        options.reject_forbidden_days(reject_forbidden_days);
+        if let Some(min_days) = min_days {
+            options.min_days(min_days);
+        }
+        if let Some(max_days) = max_days {
+            options.max_days(max_days);
+        }
+        RangeSelection::new(options.build().unwrap())
+    }
+
+    fn weekends_forbidden() -> DateConstraints {
+        DateConstraintsBuilder::default()
+            .disabled_weekdays([Weekday::Saturday, Weekday::Sunday].into_iter().collect())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn range_options_min_days_greater_than_max_days() {
+        let options = RangeOptionsBuilder::default()
+            .min_days(3)
+            .max_days(2)
+            .build();
+        assert_eq!(
+            ConfigError::MinDaysAfterMaxDays {
+                min_days: 3,
+                max_days: 2
+            },
+            options.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn select_start_and_end() {
+        let mut selection = RangeSelection::default();
+        let config = DateConstraints::default();
+        assert_eq!(
+            RangeSelectionEvent::Started(datetime!(2024-05-10 00:00)),
+            selection.select(datetime!(2024-05-10 00:00), &config)
+        );
+        assert!(!selection.is_complete());
+        assert_eq!(
+            RangeSelectionEvent::Completed(
+                datetime!(2024-05-10 00:00),
+                datetime!(2024-05-20 00:00)
+            ),
+            selection.select(datetime!(2024-05-20 00:00), &config)
+        );
+        assert!(selection.is_complete());
+        assert_eq!(
+            RangeSelectionEvent::Started(datetime!(2024-06-01 00:00)),
+            selection.select(datetime!(2024-06-01 00:00), &config)
+        );
+        assert_eq!(None, *selection.end());
+    }
+
+    #[test]
+    fn select_end_before_start() {
+        let mut selection = RangeSelection::default();
+        let config = DateConstraints::default();
+        selection.select(datetime!(2024-05-20 00:00), &config);
+        assert_eq!(
+            RangeSelectionEvent::Completed(
+                datetime!(2024-05-10 00:00),
+                datetime!(2024-05-20 00:00)
+            ),
+            selection.select(datetime!(2024-05-10 00:00), &config)
+        );
+    }
+
+    #[rstest(
+        expected, selection, end, //
+        case::forbidden_day(RangeRejection::ForbiddenDay, create_selection(None, None, false), datetime!(2024-05-11 00:00)),
+        case::too_short(RangeRejection::TooShort, create_selection(Some(3), None, false), datetime!(2024-05-09 00:00)),
+        case::too_long(RangeRejection::TooLong, create_selection(None, Some(7), false), datetime!(2024-05-16 00:00)),
+        case::includes_forbidden_day(RangeRejection::IncludesForbiddenDay, create_selection(None, None, true), datetime!(2024-05-13 00:00)),
+    )]
+    fn select_rejected(
+        expected: RangeRejection,
+        mut selection: RangeSelection,
+        end: PrimitiveDateTime,
+    ) {
+        let config = weekends_forbidden();
+        // a Thursday
+        selection.select(datetime!(2024-05-09 00:00), &config);
+        assert_eq!(
+            RangeSelectionEvent::Rejected(expected),
+            selection.select(end, &config)
+        );
+        assert_eq!(Some(datetime!(2024-05-09 00:00)), *selection.start());
+        assert_eq!(None, *selection.end());
+    }
+
+    #[rstest(
+        expected, first, second, //
+        case::forward(Some(RangeRejection::IncludesForbiddenDay), datetime!(2024-05-09 00:00), datetime!(2024-05-13 00:00)),
+        case::backward(Some(RangeRejection::IncludesForbiddenDay), datetime!(2024-05-13 00:00), datetime!(2024-05-09 00:00)),
+        case::within_week(None, datetime!(2024-05-06 00:00), datetime!(2024-05-10 00:00)),
+        case::next_days(None, datetime!(2024-05-09 00:00), datetime!(2024-05-10 00:00)),
+        case::at_max(None, datetime!(9999-12-27 00:00), datetime!(9999-12-31 23:59)),
+        case::at_min(None, datetime!(-9999-01-01 00:00), datetime!(-9999-01-01 00:00)),
+    )]
+    fn check_includes_forbidden_day(
+        expected: Option<RangeRejection>,
+        first: PrimitiveDateTime,
+        second: PrimitiveDateTime,
+    ) {
+        let selection = create_selection(None, None, true);
+        assert_eq!(
+            expected,
+            selection.check(&first, &second, &weekends_forbidden())
+        );
+    }
+
+    /// Counts the checked days, forbidding the given day
+    struct CountingConstraints {
+        forbidden: Date,
+        checked_days: Cell<usize>,
+    }
+
+    impl HasDateConstraints for CountingConstraints {
+        fn is_datetime_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            self.is_day_forbidden(date)
+        }
+
+        fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+            self.checked_days.set(self.checked_days.get() + 1);
+            date.date() == self.forbidden
+        }
+    }
+
+    #[test]
+    fn cell_flags_scan_each_day_once() {
+        let config = CountingConstraints {
+            forbidden: date!(2024 - 03 - 01),
+            checked_days: Cell::new(0),
+        };
+        let mut selection = create_selection(None, None, true);
+        selection.select(datetime!(2024-05-15 00:00), &config);
+        let cells: Vec<_> = (0..400)
+            .map(|day| datetime!(2024-01-01 00:00) + time::Duration::days(day))
+            .collect();
+        config.checked_days.set(0);
+        let selectable = cells
+            .iter()
+            .filter(|cell| selection.cell_flags(cell, &config).is_selectable)
+            .count();
+        // the days before 2024-03-01 and the forbidden day itself are not selectable
+        assert_eq!(400 - 61, selectable);
+        // each cell checks itself twice, the days between are scanned only once
+        assert!(config.checked_days.get() <= 3 * cells.len());
+    }
+
+    #[rstest(
+        end, //
+        case::min_days(datetime!(2024-05-11 00:00)),
+        case::max_days(datetime!(2024-05-15 00:00)),
+    )]
+    fn select_within_min_and_max_days(end: PrimitiveDateTime) {
+        let mut selection = create_selection(Some(3), Some(7), false);
+        let config = DateConstraints::default();
+        selection.select(datetime!(2024-05-09 00:00), &config);
+        assert!(matches!(
+            selection.select(end, &config),
+            RangeSelectionEvent::Completed(..)
+        ));
+    }
+
+    #[test]
+    fn select_forbidden_start() {
+        let mut selection = RangeSelection::default();
+        assert_eq!(
+            RangeSelectionEvent::Rejected(RangeRejection::ForbiddenDay),
+            selection.select(datetime!(2024-05-11 00:00), &weekends_forbidden())
+        );
+        assert_eq!(None, *selection.start());
+    }
+
+    #[test]
+    fn preview_with_hovered_day() {
+        let mut selection = RangeSelection::default();
+        assert_eq!(None, selection.preview());
+        selection.select(datetime!(2024-05-10 00:00), &DateConstraints::default());
+        assert_eq!(
+            Some((date!(2024 - 05 - 10), date!(2024 - 05 - 10))),
+            selection.preview()
+        );
+        selection.hover(Some(datetime!(2024-05-05 00:00)));
+        assert_eq!(
+            Some((date!(2024 - 05 - 05), date!(2024 - 05 - 10))),
+            selection.preview()
+        );
+    }
+
+    #[rstest(
+        expected, date, //
+        case::before(RangeCellFlags { is_selectable: true, ..Default::default() }, datetime!(2024-05-08 00:00)),
+        case::start(RangeCellFlags { is_range_start: true, is_in_range: true, is_range_end: false, is_selectable: true }, datetime!(2024-05-09 00:00)),
+        case::forbidden_in_range(RangeCellFlags { is_in_range: true, ..Default::default() }, datetime!(2024-05-11 00:00)),
+        case::hovered_end(RangeCellFlags { is_range_start: false, is_in_range: true, is_range_end: true, is_selectable: true }, datetime!(2024-05-14 00:00)),
+        case::too_long(RangeCellFlags::default(), datetime!(2024-05-20 00:00)),
+    )]
+    fn cell_flags(expected: RangeCellFlags, date: PrimitiveDateTime) {
+        let mut selection = create_selection(None, Some(10), false);
+        let config = weekends_forbidden();
+        selection.select(datetime!(2024-05-09 00:00), &config);
+        selection.hover(Some(datetime!(2024-05-14 00:00)));
+        assert_eq!(expected, selection.cell_flags(&date, &config));
+    }
+}
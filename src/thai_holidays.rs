@@ -0,0 +1,493 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use derive_builder::Builder;
+use derive_getters::Getters;
+use time::{
+    Date, PrimitiveDateTime, Time, Weekday, macros::format_description, util::days_in_year,
+};
+
+use crate::{
+    config::date_constraints::HasDateConstraints, locale::Locale, utils::try_from_ymd,
+    viewed_date::ViewedDate,
+};
+
+/// Thai public holiday.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ThaiHoliday {
+    /// 1 January
+    NewYear,
+    /// Makha Bucha, from a lunar table
+    MakhaBucha,
+    /// 6 April
+    Chakri,
+    /// 13 to 15 April
+    Songkran,
+    /// 1 May
+    Labour,
+    /// 4 May, since 2020
+    Coronation,
+    /// Visakha Bucha, from a lunar table
+    VisakhaBucha,
+    /// 3 June, since 2019
+    QueenSuthidaBirthday,
+    /// Asahna Bucha, from a lunar table
+    AsahnaBucha,
+    /// Buddhist Lent, the day after Asahna Bucha, from a lunar table
+    KhaoPhansa,
+    /// 28 July, since 2017
+    KingVajiralongkornBirthday,
+    /// 12 August, Mother's Day
+    QueenSirikitBirthday,
+    /// 13 October, since 2017
+    KingBhumibolMemorial,
+    /// 23 October
+    Chulalongkorn,
+    /// 5 December, Father's Day
+    KingBhumibolBirthday,
+    /// 10 December
+    Constitution,
+    /// 31 December
+    NewYearEve,
+}
+
+/// Fixed holidays as month, day and first year of the holiday.
+const FIXED_HOLIDAYS: [(ThaiHoliday, u8, u8, i32); 15] = [
+    (ThaiHoliday::NewYear, 1, 1, i32::MIN),
+    (ThaiHoliday::Chakri, 4, 6, i32::MIN),
+    (ThaiHoliday::Songkran, 4, 13, i32::MIN),
+    (ThaiHoliday::Songkran, 4, 14, i32::MIN),
+    (ThaiHoliday::Songkran, 4, 15, i32::MIN),
+    (ThaiHoliday::Labour, 5, 1, i32::MIN),
+    (ThaiHoliday::Coronation, 5, 4, 2020),
+    (ThaiHoliday::QueenSuthidaBirthday, 6, 3, 2019),
+    (ThaiHoliday::KingVajiralongkornBirthday, 7, 28, 2017),
+    (ThaiHoliday::QueenSirikitBirthday, 8, 12, i32::MIN),
+    (ThaiHoliday::KingBhumibolMemorial, 10, 13, 2017),
+    (ThaiHoliday::Chulalongkorn, 10, 23, i32::MIN),
+    (ThaiHoliday::KingBhumibolBirthday, 12, 5, i32::MIN),
+    (ThaiHoliday::Constitution, 12, 10, i32::MIN),
+    (ThaiHoliday::NewYearEve, 12, 31, i32::MIN),
+];
+
+impl ThaiHoliday {
+    /// Returns the name of the holiday, like "วันจักรี" or "Chakri Memorial Day"
+    pub const fn name(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Thai => match self {
+                ThaiHoliday::NewYear => "วันขึ้นปีใหม่",
+                ThaiHoliday::MakhaBucha => "วันมาฆบูชา",
+                ThaiHoliday::Chakri => "วันจักรี",
+                ThaiHoliday::Songkran => "วันสงกรานต์",
+                ThaiHoliday::Labour => "วันแรงงานแห่งชาติ",
+                ThaiHoliday::Coronation => "วันฉัตรมงคล",
+                ThaiHoliday::VisakhaBucha => "วันวิสาขบูชา",
+                ThaiHoliday::QueenSuthidaBirthday => "วันเฉลิมพระชนมพรรษาสมเด็จพระนางเจ้าฯ พระบรมราชินี",
+                ThaiHoliday::AsahnaBucha => "วันอาสาฬหบูชา",
+                ThaiHoliday::KhaoPhansa => "วันเข้าพรรษา",
+                ThaiHoliday::KingVajiralongkornBirthday => "วันเฉลิมพระชนมพรรษาพระบาทสมเด็จพระเจ้าอยู่หัว",
+                ThaiHoliday::QueenSirikitBirthday => "วันแม่แห่งชาติ",
+                ThaiHoliday::KingBhumibolMemorial => "วันนวมินทรมหาราช",
+                ThaiHoliday::Chulalongkorn => "วันปิยมหาราช",
+                ThaiHoliday::KingBhumibolBirthday => "วันพ่อแห่งชาติ",
+                ThaiHoliday::Constitution => "วันรัฐธรรมนูญ",
+                ThaiHoliday::NewYearEve => "วันสิ้นปี",
+            },
+            Locale::English => match self {
+                ThaiHoliday::NewYear => "New Year's Day",
+                ThaiHoliday::MakhaBucha => "Makha Bucha Day",
+                ThaiHoliday::Chakri => "Chakri Memorial Day",
+                ThaiHoliday::Songkran => "Songkran Festival",
+                ThaiHoliday::Labour => "National Labour Day",
+                ThaiHoliday::Coronation => "Coronation Day",
+                ThaiHoliday::VisakhaBucha => "Visakha Bucha Day",
+                ThaiHoliday::QueenSuthidaBirthday => "Queen Suthida's Birthday",
+                ThaiHoliday::AsahnaBucha => "Asahna Bucha Day",
+                ThaiHoliday::KhaoPhansa => "Buddhist Lent Day",
+                ThaiHoliday::KingVajiralongkornBirthday => "King Vajiralongkorn's Birthday",
+                ThaiHoliday::QueenSirikitBirthday => "Mother's Day",
+                ThaiHoliday::KingBhumibolMemorial => "King Bhumibol Memorial Day",
+                ThaiHoliday::Chulalongkorn => "Chulalongkorn Day",
+                ThaiHoliday::KingBhumibolBirthday => "Father's Day",
+                ThaiHoliday::Constitution => "Constitution Day",
+                ThaiHoliday::NewYearEve => "New Year's Eve",
+            },
+        }
+    }
+}
+
+/// Error of the parsing of a `ThaiHoliday` or of a table of lunar holidays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThaiHolidayParseError {
+    /// A line of the table is not a date followed by a holiday code
+    InvalidLine(String),
+    /// A date of the table is not a valid `YYYY-MM-DD` date
+    InvalidDate(String),
+    /// A holiday code is not one of the lunar holidays, like `makha_bucha`
+    UnknownHoliday(String),
+}
+
+impl fmt::Display for ThaiHolidayParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThaiHolidayParseError::InvalidLine(line) => write!(f, "invalid line {line}"),
+            ThaiHolidayParseError::InvalidDate(date) => write!(f, "invalid date {date}"),
+            ThaiHolidayParseError::UnknownHoliday(code) => {
+                write!(f, "unknown lunar holiday {code}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThaiHolidayParseError {}
+
+impl FromStr for ThaiHoliday {
+    type Err = ThaiHolidayParseError;
+
+    /// Parses the lunar holidays of a table, like `makha_bucha`
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "makha_bucha" => Ok(ThaiHoliday::MakhaBucha),
+            "visakha_bucha" => Ok(ThaiHoliday::VisakhaBucha),
+            "asahna_bucha" => Ok(ThaiHoliday::AsahnaBucha),
+            "khao_phansa" => Ok(ThaiHoliday::KhaoPhansa),
+            _ => Err(ThaiHolidayParseError::UnknownHoliday(code.into())),
+        }
+    }
+}
+
+/// Holiday of a day, either the holiday itself or its substitution day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HolidayDay {
+    pub holiday: ThaiHoliday,
+    pub is_substitution: bool,
+}
+
+impl HolidayDay {
+    /// Returns the name of the day, like "ชดเชยวันสงกรานต์" or "Substitution for Songkran Festival"
+    pub fn name(&self, locale: Locale) -> String {
+        let name = self.holiday.name(locale);
+        match (self.is_substitution, locale) {
+            (false, _) => name.to_string(),
+            (true, Locale::Thai) => format!("ชดเชย{name}"),
+            (true, Locale::English) => format!("Substitution for {name}"),
+        }
+    }
+}
+
+/// Parses a table of lunar holidays, one `YYYY-MM-DD code` per line,
+/// like `2025-02-12 makha_bucha`, blank lines and lines starting with `#` are skipped.
+pub fn parse_lunar_table(table: &str) -> Result<HashMap<Date, ThaiHoliday>, ThaiHolidayParseError> {
+    table
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (date, code) = line
+                .split_once(|c: char| c.is_whitespace() || c == ',')
+                .ok_or_else(|| ThaiHolidayParseError::InvalidLine(line.into()))?;
+            let date = Date::parse(date, format_description!("[year]-[month]-[day]"))
+                .map_err(|_| ThaiHolidayParseError::InvalidDate(date.into()))?;
+            Ok((date, code.trim_start_matches(',').parse()?))
+        })
+        .collect()
+}
+
+/// Thai public holidays, forbidding the holidays and their substitution days.
+#[derive(Debug, Clone, Builder, Getters)]
+#[builder(default)]
+pub struct ThaiHolidays {
+    /// Holidays following the lunar calendar, see `parse_lunar_table`
+    lunar_holidays: HashMap<Date, ThaiHoliday>,
+
+    /// If true, a holiday on a weekend is substituted by the next working day
+    substitution_days: bool,
+
+    /// Holidays of the years already computed
+    #[builder(setter(skip))]
+    #[getter(skip)]
+    cache: HolidayCache,
+}
+
+impl Default for ThaiHolidays {
+    fn default() -> Self {
+        Self {
+            lunar_holidays: HashMap::new(),
+            substitution_days: true,
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+/// Holidays with their substitution days by year, the clones start with the computed years.
+#[derive(Debug, Default)]
+struct HolidayCache(Mutex<HashMap<i32, Arc<BTreeMap<Date, HolidayDay>>>>);
+
+impl Clone for HolidayCache {
+    fn clone(&self) -> Self {
+        let years = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        Self(Mutex::new(years.clone()))
+    }
+}
+
+impl ThaiHolidays {
+    /// Returns the holiday of the given date, if any
+    pub fn holiday(&self, date: &Date) -> Option<HolidayDay> {
+        self.cached_holidays_of_year(date.year()).get(date).copied()
+    }
+
+    /// Returns the holidays of the given year, with their substitution days
+    pub fn holidays_of_year(&self, year: i32) -> BTreeMap<Date, HolidayDay> {
+        self.cached_holidays_of_year(year).as_ref().clone()
+    }
+
+    /// Returns the holidays of the given year, computed once per year
+    fn cached_holidays_of_year(&self, year: i32) -> Arc<BTreeMap<Date, HolidayDay>> {
+        let mut years = self.cache.0.lock().unwrap_or_else(PoisonError::into_inner);
+        years
+            .entry(year)
+            .or_insert_with(|| Arc::new(self.compute_holidays_of_year(year)))
+            .clone()
+    }
+
+    /// Computes the holidays of the given year, with their substitution days
+    fn compute_holidays_of_year(&self, year: i32) -> BTreeMap<Date, HolidayDay> {
+        // substitution days of the end of the previous year can fall in the year
+        let holidays: BTreeMap<Date, ThaiHoliday> = [year - 1, year]
+            .into_iter()
+            .flat_map(|year| {
+                FIXED_HOLIDAYS
+                    .iter()
+                    .filter(move |(_, _, _, since)| year >= *since)
+                    .filter_map(move |(holiday, month, day, _)| {
+                        Some((try_from_ymd(year, *month, *day).ok()?, *holiday))
+                    })
+            })
+            .chain(
+                self.lunar_holidays
+                    .iter()
+                    .filter(|(date, _)| date.year() == year - 1 || date.year() == year)
+                    .map(|(date, holiday)| (*date, *holiday)),
+            )
+            .collect();
+        let mut days: BTreeMap<Date, HolidayDay> = holidays
+            .iter()
+            .map(|(date, holiday)| {
+                let day = HolidayDay {
+                    holiday: *holiday,
+                    is_substitution: false,
+                };
+                (*date, day)
+            })
+            .collect();
+        if self.substitution_days {
+            for (date, holiday) in holidays.iter().filter(|(date, _)| is_weekend(date)) {
+                let substitution = std::iter::successors(date.next_day(), |day| day.next_day())
+                    .find(|day| !is_weekend(day) && !days.contains_key(day));
+                if let Some(substitution) = substitution {
+                    let day = HolidayDay {
+                        holiday: *holiday,
+                        is_substitution: true,
+                    };
+                    days.insert(substitution, day);
+                }
+            }
+        }
+        days.retain(|date, _| date.year() == year);
+        days
+    }
+
+    /// Returns the names of the holidays from the first to the last given dates,
+    /// to use as `GridOptions::holidays`
+    pub fn names(&self, first: &Date, last: &Date, locale: Locale) -> HashMap<Date, String> {
+        (first.year()..=last.year())
+            .flat_map(|year| {
+                self.cached_holidays_of_year(year)
+                    .iter()
+                    .filter(|(date, _)| first <= *date && *date <= last)
+                    .map(|(date, day)| (*date, day.name(locale)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+fn is_weekend(date: &Date) -> bool {
+    matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+impl HasDateConstraints for ThaiHolidays {
+    fn is_datetime_forbidden(&self, datetime: &PrimitiveDateTime) -> bool {
+        self.holiday(&datetime.date()).is_some()
+    }
+
+    fn is_day_forbidden(&self, date: &PrimitiveDateTime) -> bool {
+        self.holiday(&date.date()).is_some()
+    }
+
+    // the cached holidays of the year are looked up once instead of once per day
+    fn is_month_forbidden(&self, year_month_info: &PrimitiveDateTime) -> bool {
+        let holidays = self.cached_holidays_of_year(year_month_info.year());
+        year_month_info
+            .dates_in_month()
+            .iter()
+            .all(|day| holidays.contains_key(&day.date()))
+    }
+
+    fn is_year_forbidden(&self, year: &PrimitiveDateTime) -> bool {
+        self.cached_holidays_of_year(year.year()).len() == usize::from(days_in_year(year.year()))
+    }
+
+    // the holidays are entire days
+    fn time_boundaries(&self, _day: &PrimitiveDateTime) -> Option<Vec<Time>> {
+        Some(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::{date, datetime};
+
+    const LUNAR_TABLE: &str = "
+        # lunar holidays of 2025
+        2025-02-12 makha_bucha
+        2025-05-11 visakha_bucha
+        2025-07-10 asahna_bucha
+        2025-07-11 khao_phansa
+    ";
+
+    fn create_holidays() -> ThaiHolidays {
+        ThaiHolidaysBuilder::default()
+            .lunar_holidays(parse_lunar_table(LUNAR_TABLE).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[rstest(
+        expected, date, //
+        case::new_year(Some((ThaiHoliday::NewYear, false)), date!(2025 - 01 - 01)),
+        case::songkran(Some((ThaiHoliday::Songkran, false)), date!(2025 - 04 - 13)),
+        case::songkran_substitution(Some((ThaiHoliday::Songkran, true)), date!(2025 - 04 - 16)),
+        case::chakri_substitution(Some((ThaiHoliday::Chakri, true)), date!(2024 - 04 - 08)),
+        case::new_year_eve_substitution_next_year(Some((ThaiHoliday::NewYearEve, true)), date!(2024 - 01 - 02)),
+        case::lunar(Some((ThaiHoliday::MakhaBucha, false)), date!(2025 - 02 - 12)),
+        case::lunar_substitution(Some((ThaiHoliday::VisakhaBucha, true)), date!(2025 - 05 - 12)),
+        case::before_since(None, date!(2016 - 07 - 28)),
+        case::working_day(None, date!(2025 - 04 - 17)),
+    )]
+    fn holiday(expected: Option<(ThaiHoliday, bool)>, date: Date) {
+        let holiday = create_holidays().holiday(&date);
+        assert_eq!(
+            expected,
+            holiday.map(|day| (day.holiday, day.is_substitution))
+        );
+    }
+
+    #[test]
+    fn without_substitution_days() {
+        let holidays = ThaiHolidaysBuilder::default()
+            .substitution_days(false)
+            .build()
+            .unwrap();
+        assert_eq!(None, holidays.holiday(&date!(2025 - 04 - 16)));
+    }
+
+    #[test]
+    fn names() {
+        let names = create_holidays().names(
+            &date!(2025 - 04 - 01),
+            &date!(2025 - 04 - 30),
+            Locale::English,
+        );
+        assert_eq!(6, names.len());
+        assert_eq!(
+            Some("Substitution for Songkran Festival"),
+            names.get(&date!(2025 - 04 - 16)).map(String::as_str)
+        );
+        assert_eq!(
+            "ชดเชยวันสงกรานต์",
+            HolidayDay {
+                holiday: ThaiHoliday::Songkran,
+                is_substitution: true
+            }
+            .name(Locale::Thai)
+        );
+    }
+
+    #[rstest(
+        expected, table, //
+        case::unknown_holiday(ThaiHolidayParseError::UnknownHoliday("loy_krathong".into()), "2025-02-12 loy_krathong"),
+        case::invalid_date(ThaiHolidayParseError::InvalidDate("2025-02-30".into()), "2025-02-30 makha_bucha"),
+        case::missing_holiday(ThaiHolidayParseError::InvalidLine("2025-02-12".into()), "2025-02-12"),
+    )]
+    fn parse_lunar_table_error(expected: ThaiHolidayParseError, table: &str) {
+        assert_eq!(Err(expected), parse_lunar_table(table));
+    }
+
+    #[test]
+    fn parse_error_display() {
+        assert_eq!(
+            "unknown lunar holiday loy_krathong",
+            "loy_krathong"
+                .parse::<ThaiHoliday>()
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid line 2025-02-12",
+            ThaiHolidayParseError::InvalidLine("2025-02-12".into()).to_string()
+        );
+    }
+
+    #[test]
+    fn holidays_computed_once_per_year() {
+        let holidays = create_holidays();
+        for day in datetime!(2025-04-01 00:00).dates_in_month() {
+            holidays.is_day_forbidden(&day);
+            holidays.is_datetime_forbidden(&day);
+        }
+        holidays.is_month_forbidden(&datetime!(2025-04-01 00:00));
+        let cached = holidays.cached_holidays_of_year(2025);
+        assert!(Arc::ptr_eq(
+            &cached,
+            &holidays.cached_holidays_of_year(2025)
+        ));
+        assert_eq!(
+            vec![2025],
+            holidays
+                .cache
+                .0
+                .lock()
+                .unwrap()
+                .keys()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        // the clones keep the computed years
+        let cloned = holidays.clone();
+        assert!(Arc::ptr_eq(&cached, &cloned.cached_holidays_of_year(2025)));
+        assert_eq!(
+            holidays.holidays_of_year(2025),
+            cloned.holidays_of_year(2025)
+        );
+    }
+
+    #[test]
+    fn has_date_constraints() {
+        let holidays = create_holidays();
+        assert!(holidays.is_datetime_forbidden(&datetime!(2025-07-28 10:00)));
+        assert!(holidays.is_day_forbidden(&datetime!(2025-07-11 00:00)));
+        assert!(!holidays.is_day_forbidden(&datetime!(2025-07-14 00:00)));
+        assert!(!holidays.is_month_forbidden(&datetime!(2025-04-01 00:00)));
+        assert!(!holidays.is_year_forbidden(&datetime!(2025-01-01 00:00)));
+        assert!(!holidays.is_year_group_forbidden(&datetime!(2025-01-01 00:00)));
+    }
+}
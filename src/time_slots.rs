@@ -0,0 +1,154 @@
+use time::{Duration, PrimitiveDateTime, Time};
+
+use crate::config::date_constraints::HasDateConstraints;
+
+/// Step in minutes between two selectable times, used when `PickerConfig::minute_step` is not set
+pub const DEFAULT_MINUTE_STEP: u8 = 5;
+
+/// Returns the datetimes of the 24 hours of the day including the given datetime, for the hours view
+pub fn hours_of_day(day: &PrimitiveDateTime) -> Vec<PrimitiveDateTime> {
+    let midnight = day.replace_time(Time::MIDNIGHT);
+    (0..24)
+        .map(|hour| midnight + Duration::hours(hour))
+        .collect()
+}
+
+/// Returns the datetimes every `minute_step` minutes of the hour including the given datetime,
+/// for the minutes view, no datetime if `minute_step` is 0
+pub fn minutes_of_hour(hour: &PrimitiveDateTime, minute_step: u8) -> Vec<PrimitiveDateTime> {
+    if minute_step == 0 {
+        return Vec::new();
+    }
+    let start = hour.replace_time(Time::from_hms(hour.hour(), 0, 0).expect("the hour is valid"));
+    (0..60)
+        .step_by(minute_step.into())
+        .map(|minute| start + Duration::minutes(minute))
+        .collect()
+}
+
+/// Returns the datetimes every `minute_step` minutes of the day including the given datetime,
+/// no datetime if `minute_step` is 0
+pub fn time_slots(day: &PrimitiveDateTime, minute_step: u8) -> Vec<PrimitiveDateTime> {
+    if minute_step == 0 {
+        return Vec::new();
+    }
+    let midnight = day.replace_time(Time::MIDNIGHT);
+    (0..24 * 60)
+        .step_by(minute_step.into())
+        .map(|minute| midnight + Duration::minutes(minute))
+        .collect()
+}
+
+/// Returns the time slots of the day including the given datetime that are not forbidden
+pub fn allowed_time_slots<T: HasDateConstraints>(
+    config: &T,
+    day: &PrimitiveDateTime,
+    minute_step: u8,
+) -> Vec<PrimitiveDateTime> {
+    time_slots(day, minute_step)
+        .into_iter()
+        .filter(|slot| !config.is_datetime_forbidden(slot))
+        .collect()
+}
+
+/// Returns the given datetime rounded down to the previous time slot,
+/// to the minute if `minute_step` is 0
+pub fn floor_to_time_slot(date: &PrimitiveDateTime, minute_step: u8) -> PrimitiveDateTime {
+    let minute = date.minute() - date.minute().checked_rem(minute_step).unwrap_or(0);
+    date.replace_time(Time::from_hms(date.hour(), minute, 0).expect("the minute is valid"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::date_constraints::DateConstraintsBuilder;
+
+    use rstest::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn hours_of_day_from_midnight() {
+        let hours = hours_of_day(&datetime!(2024-05-15 10:30));
+        assert_eq!(24, hours.len());
+        assert_eq!(datetime!(2024-05-15 00:00), hours[0]);
+        assert_eq!(datetime!(2024-05-15 23:00), hours[23]);
+    }
+
+    #[rstest(
+        expected_len, expected_last, minute_step, //
+        case::every_minute(60, datetime!(2024-05-15 10:59), 1),
+        case::quarter_hours(4, datetime!(2024-05-15 10:45), 15),
+        case::whole_hour(1, datetime!(2024-05-15 10:00), 60),
+    )]
+    fn minutes_of_hour_by_step(
+        expected_len: usize,
+        expected_last: PrimitiveDateTime,
+        minute_step: u8,
+    ) {
+        let minutes = minutes_of_hour(&datetime!(2024-05-15 10:30), minute_step);
+        assert_eq!(expected_len, minutes.len());
+        assert_eq!(datetime!(2024-05-15 10:00), minutes[0]);
+        assert_eq!(expected_last, *minutes.last().unwrap());
+    }
+
+    #[rstest(
+        expected_len, minute_step, //
+        case::five_minutes(288, 5),
+        case::half_hours(48, 30),
+    )]
+    fn time_slots_by_step(expected_len: usize, minute_step: u8) {
+        assert_eq!(
+            expected_len,
+            time_slots(&datetime!(2024-05-15 10:30), minute_step).len()
+        );
+    }
+
+    #[test]
+    fn zero_step() {
+        let date = datetime!(2024-05-15 10:44:59);
+        assert!(minutes_of_hour(&date, 0).is_empty());
+        assert!(time_slots(&date, 0).is_empty());
+        assert!(
+            allowed_time_slots(
+                &DateConstraintsBuilder::default().build().unwrap(),
+                &date,
+                0
+            )
+            .is_empty()
+        );
+        assert_eq!(datetime!(2024-05-15 10:44), floor_to_time_slot(&date, 0));
+    }
+
+    #[test]
+    fn allowed_time_slots_between_min_and_max() {
+        let config = DateConstraintsBuilder::default()
+            .min_datetime(datetime!(2024-05-15 08:10))
+            .max_datetime(datetime!(2024-05-15 10:00))
+            .build()
+            .unwrap();
+        let slots = allowed_time_slots(&config, &datetime!(2024-05-15 00:00), 30);
+        assert_eq!(
+            vec![
+                datetime!(2024-05-15 08:30),
+                datetime!(2024-05-15 09:00),
+                datetime!(2024-05-15 09:30),
+                datetime!(2024-05-15 10:00),
+            ],
+            slots
+        );
+    }
+
+    #[rstest(
+        expected, date, minute_step, //
+        case::on_slot(datetime!(2024-05-15 10:30), datetime!(2024-05-15 10:30), 15),
+        case::between_slots(datetime!(2024-05-15 10:30), datetime!(2024-05-15 10:44:59), 15),
+        case::every_minute(datetime!(2024-05-15 10:44), datetime!(2024-05-15 10:44:59), 1),
+    )]
+    fn floor_to_time_slot_by_step(
+        expected: PrimitiveDateTime,
+        date: PrimitiveDateTime,
+        minute_step: u8,
+    ) {
+        assert_eq!(expected, floor_to_time_slot(&date, minute_step));
+    }
+}
@@ -1,8 +1,10 @@
-use time::{Date, Month, PrimitiveDateTime, Time};
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, error::ComponentRange};
 
 use crate::{
-    config::date_constraints::HasDateConstraints, dialog_view_type::DialogViewType,
-    viewed_date::ViewedDate,
+    calendar_era::CalendarEra,
+    config::date_constraints::HasDateConstraints,
+    dialog_view_type::DialogViewType,
+    viewed_date::{ViewedDate, YEARS_IN_YEAR_SELECTION, year_group_end, year_group_start},
 };
 
 /// Returns true if the "previous" button should be displayed.
@@ -11,47 +13,314 @@ pub fn should_display_previous_button<T: HasDateConstraints>(
     viewed_date: &PrimitiveDateTime,
     config: &T,
 ) -> bool {
+    should_display_previous_button_in(
+        &CalendarEra::Christian,
+        dialog_view_type,
+        viewed_date,
+        config,
+    )
+}
+
+/// Returns true if the "next" button should be displayed.
+pub fn should_display_next_button<T: HasDateConstraints>(
+    dialog_view_type: &DialogViewType,
+    viewed_date: &PrimitiveDateTime,
+    config: &T,
+) -> bool {
+    should_display_next_button_in(
+        &CalendarEra::Christian,
+        dialog_view_type,
+        viewed_date,
+        config,
+    )
+}
+
+/// Returns true if the "previous" button should be displayed,
+/// the year groups are aligned on the years of the given era.
+pub fn should_display_previous_button_in<T: HasDateConstraints>(
+    era: &CalendarEra,
+    dialog_view_type: &DialogViewType,
+    viewed_date: &PrimitiveDateTime,
+    config: &T,
+) -> bool {
+    // the navigation saturates at the edges of the range of `Date`
     match dialog_view_type {
-        DialogViewType::Days => !config.is_month_forbidden(&viewed_date.previous_month()),
-        DialogViewType::Months => !config.is_year_forbidden(&viewed_date.previous_year()),
+        DialogViewType::Weeks | DialogViewType::Days => {
+            let previous = viewed_date.previous_month();
+            previous != *viewed_date && !config.is_month_forbidden(&previous)
+        }
+        DialogViewType::Hours => {
+            let previous = viewed_date.previous_day();
+            previous != *viewed_date && !config.is_day_forbidden(&previous)
+        }
+        DialogViewType::Minutes => {
+            let previous = viewed_date.previous_hour();
+            previous != *viewed_date && !config.is_hour_forbidden(&previous)
+        }
+        DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+            let previous = viewed_date.previous_year();
+            previous != *viewed_date && !config.is_year_forbidden(&previous)
+        }
         DialogViewType::Years => {
-            !config.is_year_group_forbidden(&viewed_date.previous_year_group())
+            let previous = viewed_date.previous_year_group_in(era);
+            previous != *viewed_date && !is_year_group_forbidden_in(config, era, &previous)
         }
     }
 }
 
-/// Returns true if the "next" button should be displayed.
-pub fn should_display_next_button<T: HasDateConstraints>(
+/// Returns true if the "next" button should be displayed,
+/// the year groups are aligned on the years of the given era.
+pub fn should_display_next_button_in<T: HasDateConstraints>(
+    era: &CalendarEra,
     dialog_view_type: &DialogViewType,
     viewed_date: &PrimitiveDateTime,
     config: &T,
 ) -> bool {
+    // the navigation saturates at the edges of the range of `Date`
     match dialog_view_type {
-        DialogViewType::Days => !config.is_month_forbidden(&viewed_date.next_month()),
-        DialogViewType::Months => !config.is_year_forbidden(&viewed_date.next_year()),
-        DialogViewType::Years => !config.is_year_group_forbidden(&viewed_date.next_year_group()),
+        DialogViewType::Weeks | DialogViewType::Days => {
+            let next = viewed_date.next_month();
+            next != *viewed_date && !config.is_month_forbidden(&next)
+        }
+        DialogViewType::Hours => {
+            let next = viewed_date.next_day();
+            next != *viewed_date && !config.is_day_forbidden(&next)
+        }
+        DialogViewType::Minutes => {
+            let next = viewed_date.next_hour();
+            next != *viewed_date && !config.is_hour_forbidden(&next)
+        }
+        DialogViewType::HalfYears | DialogViewType::Quarters | DialogViewType::Months => {
+            let next = viewed_date.next_year();
+            next != *viewed_date && !config.is_year_forbidden(&next)
+        }
+        DialogViewType::Years => {
+            let next = viewed_date.next_year_group_in(era);
+            next != *viewed_date && !is_year_group_forbidden_in(config, era, &next)
+        }
     }
 }
 
-/// Returns a `Date`
+/// Returns true if the year, half year, quarter, month, day, hour or minute including the given datetime
+/// is forbidden, depending on the scale of the given view type.
+/// For weeks, the given datetime is the first day of the week.
+pub fn is_forbidden_in_view<T: HasDateConstraints>(
+    config: &T,
+    dialog_view_type: &DialogViewType,
+    date: &PrimitiveDateTime,
+) -> bool {
+    match dialog_view_type {
+        DialogViewType::Years => config.is_year_forbidden(date),
+        DialogViewType::HalfYears => config.is_half_year_forbidden(date),
+        DialogViewType::Quarters => config.is_quarter_forbidden(date),
+        DialogViewType::Months => config.is_month_forbidden(date),
+        DialogViewType::Weeks => config.is_week_forbidden(date),
+        DialogViewType::Days => config.is_day_forbidden(date),
+        DialogViewType::Hours => config.is_hour_forbidden(date),
+        DialogViewType::Minutes => config.is_datetime_forbidden(date),
+    }
+}
+
+/// Returns true if the entire group of years of the given era including the given year is forbidden.
+pub fn is_year_group_forbidden_in<T: HasDateConstraints>(
+    config: &T,
+    era: &CalendarEra,
+    year: &PrimitiveDateTime,
+) -> bool {
+    if *era == CalendarEra::Christian {
+        return config.is_year_group_forbidden(year);
+    }
+    // the days out of the range of `Date` can not be selected
+    let group_start = era.year_group_start_of(&year.date());
+    let first_day = era.first_day_of_year(group_start).unwrap_or(Date::MIN);
+    let end = era
+        .first_day_of_year(group_start + YEARS_IN_YEAR_SELECTION)
+        .ok();
+    let mut month = PrimitiveDateTime::new(first_day, year.time());
+    loop {
+        // whole calendar years are checked at once, the months of a year starting in April one by one
+        let next_year = month.next_year();
+        let (is_forbidden, next) =
+            if month.month() == Month::January && end.is_none_or(|end| next_year.date() <= end) {
+                (config.is_year_forbidden(&month), next_year)
+            } else {
+                (config.is_month_forbidden(&month), month.next_month())
+            };
+        if !is_forbidden {
+            return false;
+        }
+        if next == month || end.is_some_and(|end| next.date() >= end) {
+            return true;
+        }
+        month = next;
+    }
+}
+
+/// Number of years searched for an allowed day in a direction without `HasDateConstraints::min_bound`
+/// or `HasDateConstraints::max_bound`
+pub const MAX_SEARCHED_YEARS: i32 = 100;
+
+/// Returns the allowed day nearest to the given datetime, at the allowed time of that day
+/// nearest to the given time, the later day or time is returned if two are equally near.
+/// The search is bounded by `HasDateConstraints::min_bound` and `HasDateConstraints::max_bound`,
+/// or by `MAX_SEARCHED_YEARS` in a direction without a bound,
+/// returns None if no allowed day was found in both directions.
+pub fn nearest_allowed_day<T: HasDateConstraints>(
+    config: &T,
+    datetime: &PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if !config.is_day_forbidden(datetime) {
+        return Some(nearest_allowed_time(config, datetime));
+    }
+    let later = first_allowed_day_towards(config, datetime, true);
+    let earlier = first_allowed_day_towards(config, datetime, false);
+    match (earlier, later) {
+        (Some(earlier), Some(later)) if *datetime - earlier < later - *datetime => Some(earlier),
+        (earlier, later) => later.or(earlier),
+    }
+    .map(|day| nearest_allowed_time(config, &day))
+}
+
+/// Returns the allowed minute of the day of the given datetime nearest to it,
+/// the later one if two are equally near, the given datetime if it is allowed
+/// or if no minute of the day is allowed
+fn nearest_allowed_time<T: HasDateConstraints>(
+    config: &T,
+    datetime: &PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    if !config.is_datetime_forbidden(datetime) {
+        return *datetime;
+    }
+    let midnight = datetime.replace_time(Time::MIDNIGHT);
+    (0..24)
+        .map(|hour| midnight + Duration::hours(hour))
+        .filter(|hour| !config.is_hour_forbidden(hour))
+        .flat_map(|hour| (0..60).map(move |minute| hour + Duration::minutes(minute)))
+        .filter(|minute| !config.is_datetime_forbidden(minute))
+        .min_by_key(|minute| ((*minute - *datetime).abs(), minute < datetime))
+        .unwrap_or(*datetime)
+}
+
+/// Returns the first allowed day from the given datetime towards the future if `forward`,
+/// otherwise towards the past
+fn first_allowed_day_towards<T: HasDateConstraints>(
+    config: &T,
+    datetime: &PrimitiveDateTime,
+    forward: bool,
+) -> Option<PrimitiveDateTime> {
+    let min = config.min_bound().map(|min| min.date());
+    let max = config.max_bound().map(|max| max.date());
+    // the search starts at the bound it is heading to, if the given datetime is beyond it
+    let (start, limit) = if forward {
+        let limit = max
+            .or_else(|| try_from_ymd(datetime.year() + MAX_SEARCHED_YEARS, 12, 31).ok())
+            .unwrap_or(Date::MAX);
+        (min.filter(|min| *min > datetime.date()), limit)
+    } else {
+        let limit = min
+            .or_else(|| try_from_ymd(datetime.year() - MAX_SEARCHED_YEARS, 1, 1).ok())
+            .unwrap_or(Date::MIN);
+        (max.filter(|max| *max < datetime.date()), limit)
+    };
+    let is_beyond_limit = |day: &PrimitiveDateTime| {
+        if forward {
+            day.date() > limit
+        } else {
+            day.date() < limit
+        }
+    };
+    let step = if forward {
+        Duration::days(1)
+    } else {
+        Duration::days(-1)
+    };
+    let mut day = start.map_or(*datetime, |start| {
+        PrimitiveDateTime::new(start, datetime.time())
+    });
+    while !is_beyond_limit(&day) {
+        // `day` is the first day to check in its month
+        if !config.is_month_forbidden(&day.first_day_of_month()) {
+            let month = day.month();
+            let mut candidate = Some(day);
+            while let Some(d) = candidate.filter(|d| d.month() == month && !is_beyond_limit(d)) {
+                if !config.is_day_forbidden(&d) {
+                    return Some(d);
+                }
+                candidate = d.checked_add(step);
+            }
+        }
+        let next = if forward {
+            day.first_day_of_month()
+                .checked_add(Duration::days(day.month().length(day.year()).into()))?
+        } else {
+            day.first_day_of_month().checked_sub(Duration::days(1))?
+        };
+        if next.year() != day.year() {
+            // continue from the last day to check of a forbidden year group or year
+            let skipped_year = if year_group_start(next.year()) != year_group_start(day.year())
+                && config.is_year_group_forbidden(&next)
+            {
+                Some(if forward {
+                    year_group_end(next.year())
+                } else {
+                    year_group_start(next.year())
+                })
+            } else if config.is_year_forbidden(&next) {
+                Some(next.year())
+            } else {
+                None
+            };
+            if let Some(year) = skipped_year {
+                let date = if forward {
+                    try_from_ymd(year, 12, 31)
+                } else {
+                    try_from_ymd(year, 1, 1)
+                };
+                day = PrimitiveDateTime::new(date.ok()?, next.time());
+                continue;
+            }
+        }
+        day = next;
+    }
+    None
+}
+
+/// Returns a `Date`, or an error if the date does not exist or is out of the range of `Date`
+pub fn try_from_ymd(year: i32, month: u8, day: u8) -> Result<Date, ComponentRange> {
+    Date::from_calendar_date(year, Month::try_from(month)?, day)
+}
+
+/// Returns a `PrimitiveDateTime`, or an error if the datetime does not exist
+/// or is out of the range of `Date`
+pub fn try_from_ymdhm(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+) -> Result<PrimitiveDateTime, ComponentRange> {
+    Ok(PrimitiveDateTime::new(
+        try_from_ymd(year, month, day)?,
+        Time::from_hms(hour, minute, 0)?,
+    ))
+}
+
+/// Returns a `Date`, panics if the date does not exist, see `try_from_ymd`
 pub fn from_ymd(year: i32, month: u8, day: u8) -> Date {
-    let m = Month::try_from(month).expect("invalid or out-of-range month");
-    Date::from_calendar_date(year, m, day).expect("invalid or out-of-range date")
+    try_from_ymd(year, month, day).expect("invalid or out-of-range date")
 }
 
-/// Returns a `PrimitiveDateTime`
+/// Returns a `PrimitiveDateTime`, panics if the datetime does not exist, see `try_from_ymdhm`
 pub fn from_ymdhm(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
-    let m = Month::try_from(month).expect("invalid or out-of-range month");
-    PrimitiveDateTime::new(
-        Date::from_calendar_date(year, m, day).expect("invalid or out-of-range date"),
-        Time::from_hms(hour, minute, 0).expect("invalid or out-of-range time"),
-    )
+    try_from_ymdhm(year, month, day, hour, minute).expect("invalid or out-of-range datetime")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::date_constraints::MockHasDateConstraints;
+    use crate::config::date_constraints::{
+        DateConstraints, DateConstraintsBuilder, MockHasDateConstraints,
+    };
 
     use crate::rstest_utils::create_datetime;
     use mockall::predicate;
@@ -128,4 +397,111 @@ mod tests {
             should_display_next_button(&dialog_view_type, &viewed_date, &mock_constraints)
         );
     }
+
+    #[fixture(retval = false)]
+    fn buddhist_year_group_forbidden(retval: bool) -> MockHasDateConstraints {
+        let mut mock = MockHasDateConstraints::default();
+        mock.expect_is_year_forbidden()
+            .withf(|year| (1997..=2016).contains(&year.year()))
+            .times(if retval { 20 } else { 1 })
+            .returning(move |_| retval);
+        mock
+    }
+
+    #[rstest(
+        expected, viewed_date, mock_constraints, //
+        case::year_group_forbidden(false, create_datetime(2024, 2, 16, 0, 0), buddhist_year_group_forbidden(true)),
+        case::year_group_allowed(true, create_datetime(2017, 1, 1, 0, 0), buddhist_year_group_forbidden(false)),
+    )]
+    fn test_should_display_previous_button_in_buddhist_era(
+        expected: bool,
+        viewed_date: PrimitiveDateTime,
+        mock_constraints: MockHasDateConstraints,
+    ) {
+        let era = CalendarEra::Buddhist {
+            april_new_year: false,
+        };
+        assert_eq!(
+            expected,
+            should_display_previous_button_in(
+                &era,
+                &DialogViewType::Years,
+                &viewed_date,
+                &mock_constraints
+            )
+        );
+    }
+
+    #[rstest(
+        expected, april_new_year, min_datetime, //
+        case::buddhist_forbidden(true, false, create_datetime(1937, 3, 1, 0, 0)),
+        case::april_new_year_allowed_in_march(false, true, create_datetime(1937, 3, 1, 0, 0)),
+        case::april_new_year_forbidden(true, true, create_datetime(1937, 4, 1, 0, 0)),
+        case::april_new_year_allowed_in_april(false, true, create_datetime(1917, 3, 1, 0, 0)),
+    )]
+    fn is_year_group_forbidden_in_april_new_year(
+        expected: bool,
+        april_new_year: bool,
+        min_datetime: PrimitiveDateTime,
+    ) {
+        // the Buddhist year group 2460-2479 is from April 1916 to March 1937 with `april_new_year`
+        let config = DateConstraintsBuilder::default()
+            .min_datetime(min_datetime)
+            .build()
+            .unwrap();
+        let era = CalendarEra::Buddhist { april_new_year };
+        assert_eq!(
+            expected,
+            is_year_group_forbidden_in(&config, &era, &create_datetime(1930, 5, 1, 0, 0))
+        );
+    }
+
+    #[rstest(
+        year, month, day, //
+        case::invalid_month(2024, 13, 1),
+        case::invalid_day(2024, 2, 30),
+        case::after_max(10000, 1, 1),
+        case::before_min(-10000, 12, 31),
+    )]
+    fn try_from_ymd_error(year: i32, month: u8, day: u8) {
+        assert!(try_from_ymd(year, month, day).is_err());
+        assert!(try_from_ymdhm(year, month, day, 0, 0).is_err());
+    }
+
+    #[test]
+    fn try_from_ymdhm_at_edges() {
+        assert_eq!(Ok(Date::MAX), try_from_ymd(9999, 12, 31));
+        assert_eq!(Ok(Date::MIN), try_from_ymd(-9999, 1, 1));
+        assert!(try_from_ymdhm(2024, 1, 1, 24, 0).is_err());
+    }
+
+    #[rstest(
+        dialog_view_type, //
+        case::years(DialogViewType::Years),
+        case::months(DialogViewType::Months),
+        case::days(DialogViewType::Days),
+        case::hours(DialogViewType::Hours),
+        case::minutes(DialogViewType::Minutes),
+    )]
+    fn no_button_at_edges(dialog_view_type: DialogViewType) {
+        let config = DateConstraints::default();
+        let min = create_datetime(-9999, 1, 1, 0, 0);
+        let max = create_datetime(9999, 12, 31, 23, 0);
+        assert!(!should_display_previous_button(
+            &dialog_view_type,
+            &min,
+            &config
+        ));
+        assert!(!should_display_next_button(
+            &dialog_view_type,
+            &max,
+            &config
+        ));
+        assert!(should_display_next_button(&dialog_view_type, &min, &config));
+        assert!(should_display_previous_button(
+            &dialog_view_type,
+            &max,
+            &config
+        ));
+    }
 }
@@ -1,23 +1,71 @@
 use std::ops::RangeInclusive;
 
-use time::{Duration, PrimitiveDateTime};
+use time::{Duration, Month, PrimitiveDateTime, Time, Weekday};
 
-use crate::{dialog_view_type::DialogViewType, utils::from_ymd};
+use crate::{
+    calendar_era::CalendarEra,
+    dialog_view_type::DialogViewType,
+    utils::{from_ymd, try_from_ymd},
+};
 
 pub const YEARS_IN_YEAR_SELECTION: i32 = 20;
 
 pub type YearNumber = i32;
 pub type MonthNumber = u8;
 pub type DayNumber = u8;
+pub type QuarterNumber = u8;
+pub type HalfYearNumber = u8;
 
 /// Trait used for the variable that describes the currently viewed datepicker.
+/// The `previous_*`, `next_*` and `add_*` methods saturate at the edges of the range of `Date`,
+/// returning the given datetime unchanged if the period does not exist.
 pub trait ViewedDate {
+    /// Returns a datetime with the start of the previous hour
+    fn previous_hour(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the start of the next hour
+    fn next_hour(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the previous day at the same time
+    fn previous_day(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the next day at the same time
+    fn next_day(&self) -> PrimitiveDateTime;
+
     /// Returns a datetime with the first day of the previous month
     fn previous_month(&self) -> PrimitiveDateTime;
 
     /// Returns a datetime with the first day of the next month
     fn next_month(&self) -> PrimitiveDateTime;
 
+    /// Returns a datetime with the same day of the previous month at the same time,
+    /// the last day of the previous month if it is shorter
+    fn previous_month_same_day(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the same day of the next month at the same time,
+    /// the last day of the next month if it is shorter
+    fn next_month_same_day(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the same day the given number of months later, or earlier
+    /// if negative, at the same time, the last day of the month if it is shorter
+    fn add_months(&self, months: i32) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the same day and month the given number of years later,
+    /// or earlier if negative, at the same time, 29 February becomes 28 February in common years
+    fn add_years(&self, years: i32) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the first day of the previous quarter
+    fn previous_quarter(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the first day of the next quarter
+    fn next_quarter(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the first day of the previous half year
+    fn previous_half_year(&self) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the first day of the next half year
+    fn next_half_year(&self) -> PrimitiveDateTime;
+
     /// Returns a datetime with the first day of the previous year
     fn previous_year(&self) -> PrimitiveDateTime;
 
@@ -30,9 +78,29 @@ pub trait ViewedDate {
     /// Returns a datetime with the first day of the first year of the next year group
     fn next_year_group(&self) -> PrimitiveDateTime;
 
+    /// Returns a datetime with the first day of the last year of the previous year group,
+    /// the year groups are aligned on the years of the given era
+    fn previous_year_group_in(&self, era: &CalendarEra) -> PrimitiveDateTime;
+
+    /// Returns a datetime with the first day of the first year of the next year group,
+    /// the year groups are aligned on the years of the given era
+    fn next_year_group_in(&self, era: &CalendarEra) -> PrimitiveDateTime;
+
     /// Returns a datetime with the first day of the currently set month
     fn first_day_of_month(&self) -> PrimitiveDateTime;
 
+    /// Returns the quarter of the currently set month, from 1 to 4
+    fn quarter(&self) -> QuarterNumber;
+
+    /// Returns a datetime with the first day of the currently set quarter
+    fn first_day_of_quarter(&self) -> PrimitiveDateTime;
+
+    /// Returns the half year of the currently set month, 1 or 2
+    fn half_year(&self) -> HalfYearNumber;
+
+    /// Returns a datetime with the first day of the currently set half year
+    fn first_day_of_half_year(&self) -> PrimitiveDateTime;
+
     // wait for std::iter::Step to Stabilized
     // https://docs.rs/rustc-std-workspace-std/latest/std/iter/trait.Step.html
     /// Return all datetimes in the currently set month
@@ -42,70 +110,188 @@ pub trait ViewedDate {
     // https://docs.rs/rustc-std-workspace-std/latest/std/iter/trait.Step.html
     /// Return datetimes start with `Sunday before Self` as `first day`,
     /// fill 7 x n row of calendar with the whole currently set month insided
+    #[deprecated(note = "use `calendar_dates` with the first weekday of the calendar")]
     fn dates_fill_calendar(&self, offset: u8) -> Vec<PrimitiveDateTime>;
 
-    /// Returns true if the currently `ViewedDate` with the given `DialogViewType` includes the given datetime
+    /// Return datetimes of the rows of 7 days of the calendar of the currently set month,
+    /// from the `first_weekday` on or before the first day of the month.
+    /// Returns always 6 rows if `six_weeks`, otherwise only the rows including a day of the month.
+    fn calendar_dates(&self, first_weekday: Weekday, six_weeks: bool) -> Vec<PrimitiveDateTime>;
+
+    /// Returns a datetime with the last `first_weekday` on or before the currently set day
+    fn week_start(&self, first_weekday: Weekday) -> PrimitiveDateTime;
+
+    /// Returns true if the currently `ViewedDate` with the given `DialogViewType` includes the given datetime,
+    /// weeks are ISO-8601 weeks starting on Monday
     fn contains(&self, dialog_view_type: &DialogViewType, date: &PrimitiveDateTime) -> bool;
+
+    /// Returns true if the currently `ViewedDate` with the given `DialogViewType` includes the given datetime,
+    /// years are compared as calendar years of the given era and weeks start on the given weekday,
+    /// see `FiscalCalendar::fiscal_year` to compare fiscal years
+    fn contains_in(
+        &self,
+        era: &CalendarEra,
+        first_weekday: Weekday,
+        dialog_view_type: &DialogViewType,
+        date: &PrimitiveDateTime,
+    ) -> bool;
 }
 
 impl ViewedDate for PrimitiveDateTime {
+    fn previous_hour(&self) -> PrimitiveDateTime {
+        self.replace_time(Time::from_hms(self.hour(), 0, 0).expect("the hour is valid"))
+            .checked_sub(Duration::hours(1))
+            .unwrap_or(*self)
+    }
+
+    fn next_hour(&self) -> PrimitiveDateTime {
+        self.replace_time(Time::from_hms(self.hour(), 0, 0).expect("the hour is valid"))
+            .checked_add(Duration::hours(1))
+            .unwrap_or(*self)
+    }
+
+    fn previous_day(&self) -> PrimitiveDateTime {
+        self.checked_sub(Duration::days(1)).unwrap_or(*self)
+    }
+
+    fn next_day(&self) -> PrimitiveDateTime {
+        self.checked_add(Duration::days(1)).unwrap_or(*self)
+    }
+
     fn previous_month(&self) -> PrimitiveDateTime {
-        let mut year = self.year();
-        let mut month = self.month() as u8;
-        if month == 1 {
-            month = 12;
-            year -= 1;
-        } else {
-            month -= 1;
-        }
-        PrimitiveDateTime::new(from_ymd(year, month, 1), self.time())
+        let (year, month) = match self.month() as u8 {
+            1 => (self.year() - 1, 12),
+            month => (self.year(), month - 1),
+        };
+        first_day_or(self, year, month)
     }
 
     fn next_month(&self) -> PrimitiveDateTime {
-        let mut year = self.year();
-        let mut month = self.month() as u8;
-        if month == 12 {
-            month = 1;
-            year += 1;
-        } else {
-            month += 1;
-        }
-        PrimitiveDateTime::new(from_ymd(year, month, 1), self.time())
+        let (year, month) = match self.month() as u8 {
+            12 => (self.year() + 1, 1),
+            month => (self.year(), month + 1),
+        };
+        first_day_or(self, year, month)
+    }
+
+    fn previous_month_same_day(&self) -> PrimitiveDateTime {
+        self.add_months(-1)
+    }
+
+    fn next_month_same_day(&self) -> PrimitiveDateTime {
+        self.add_months(1)
+    }
+
+    fn add_months(&self, months: i32) -> PrimitiveDateTime {
+        let total =
+            i64::from(self.year()) * 12 + i64::from(self.month() as u8 - 1) + i64::from(months);
+        let Ok(year) = YearNumber::try_from(total.div_euclid(12)) else {
+            return *self;
+        };
+        let month = total.rem_euclid(12) as MonthNumber + 1;
+        let length = Month::try_from(month)
+            .expect("the month is valid")
+            .length(year);
+        try_from_ymd(year, month, self.day().min(length))
+            .map(|date| PrimitiveDateTime::new(date, self.time()))
+            .unwrap_or(*self)
+    }
+
+    fn add_years(&self, years: i32) -> PrimitiveDateTime {
+        self.add_months(years.saturating_mul(12))
+    }
+
+    fn previous_quarter(&self) -> PrimitiveDateTime {
+        let (year, quarter) = match self.quarter() {
+            1 => (self.year() - 1, 4),
+            quarter => (self.year(), quarter - 1),
+        };
+        first_day_or(self, year, (quarter - 1) * 3 + 1)
+    }
+
+    fn next_quarter(&self) -> PrimitiveDateTime {
+        let (year, quarter) = match self.quarter() {
+            4 => (self.year() + 1, 1),
+            quarter => (self.year(), quarter + 1),
+        };
+        first_day_or(self, year, (quarter - 1) * 3 + 1)
+    }
+
+    fn previous_half_year(&self) -> PrimitiveDateTime {
+        let (year, month) = match self.half_year() {
+            1 => (self.year() - 1, 7),
+            _ => (self.year(), 1),
+        };
+        first_day_or(self, year, month)
+    }
+
+    fn next_half_year(&self) -> PrimitiveDateTime {
+        let (year, month) = match self.half_year() {
+            1 => (self.year(), 7),
+            _ => (self.year() + 1, 1),
+        };
+        first_day_or(self, year, month)
     }
 
     fn previous_year(&self) -> PrimitiveDateTime {
-        PrimitiveDateTime::new(from_ymd(self.year() - 1, 1, 1), self.time())
+        first_day_or(self, self.year() - 1, 1)
     }
 
     fn next_year(&self) -> PrimitiveDateTime {
-        PrimitiveDateTime::new(from_ymd(self.year() + 1, 1, 1), self.time())
+        first_day_or(self, self.year() + 1, 1)
     }
 
     fn previous_year_group(&self) -> PrimitiveDateTime {
-        PrimitiveDateTime::new(
-            from_ymd(year_group_start(self.year()) - 1, 1, 1),
-            self.time(),
-        )
+        self.previous_year_group_in(&CalendarEra::Christian)
     }
 
     fn next_year_group(&self) -> PrimitiveDateTime {
-        PrimitiveDateTime::new(from_ymd(year_group_end(self.year()) + 1, 1, 1), self.time())
+        self.next_year_group_in(&CalendarEra::Christian)
+    }
+
+    fn previous_year_group_in(&self, era: &CalendarEra) -> PrimitiveDateTime {
+        let year = era.year_group_start_of(&self.date()) - 1;
+        first_day_of_era_year_or(self, era, year)
+    }
+
+    fn next_year_group_in(&self, era: &CalendarEra) -> PrimitiveDateTime {
+        let year = era.year_group_start_of(&self.date()) + YEARS_IN_YEAR_SELECTION;
+        first_day_of_era_year_or(self, era, year)
     }
 
     fn first_day_of_month(&self) -> PrimitiveDateTime {
         PrimitiveDateTime::new(from_ymd(self.year(), self.month() as u8, 1), self.time())
     }
 
+    fn quarter(&self) -> QuarterNumber {
+        (self.month() as u8 - 1) / 3 + 1
+    }
+
+    fn first_day_of_quarter(&self) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            from_ymd(self.year(), (self.quarter() - 1) * 3 + 1, 1),
+            self.time(),
+        )
+    }
+
+    fn half_year(&self) -> HalfYearNumber {
+        (self.month() as u8 - 1) / 6 + 1
+    }
+
+    fn first_day_of_half_year(&self) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            from_ymd(self.year(), (self.half_year() - 1) * 6 + 1, 1),
+            self.time(),
+        )
+    }
+
     // wait for std::iter::Step to Stabilized
     // https://docs.rs/rustc-std-workspace-std/latest/std/iter/trait.Step.html
     fn dates_in_month(&self) -> Vec<Self> {
-        let mut dates = Vec::new();
-        let mut d = self.first_day_of_month();
-        while d < self.next_month() {
-            dates.push(d);
-            d = d + Duration::days(1);
-        }
-        dates
+        let first_day = self.first_day_of_month();
+        (0..self.month().length(self.year()))
+            .map(|day| first_day + Duration::days(day.into()))
+            .collect()
     }
 
     // wait for std::iter::Step to Stabilized
@@ -123,17 +309,99 @@ impl ViewedDate for PrimitiveDateTime {
             .collect()
     }
 
+    fn calendar_dates(&self, first_weekday: Weekday, six_weeks: bool) -> Vec<Self> {
+        let first_day = self.first_day_of_month();
+        let offset = days_since(first_day.weekday(), first_weekday);
+        let rows = if six_weeks {
+            6
+        } else {
+            (offset + first_day.month().length(first_day.year())).div_ceil(7)
+        };
+        // the days out of the range of `Date` are skipped
+        (0..i64::from(rows) * 7)
+            .filter_map(|day| first_day.checked_add(Duration::days(day - i64::from(offset))))
+            .collect()
+    }
+
+    fn week_start(&self, first_weekday: Weekday) -> Self {
+        self.checked_sub(Duration::days(
+            days_since(self.weekday(), first_weekday).into(),
+        ))
+        .unwrap_or(*self)
+    }
+
     fn contains(&self, dialog_view_type: &DialogViewType, date: &Self) -> bool {
+        self.contains_in(
+            &CalendarEra::Christian,
+            Weekday::Monday,
+            dialog_view_type,
+            date,
+        )
+    }
+
+    fn contains_in(
+        &self,
+        era: &CalendarEra,
+        first_weekday: Weekday,
+        dialog_view_type: &DialogViewType,
+        date: &Self,
+    ) -> bool {
         match dialog_view_type {
-            DialogViewType::Years => self.year() == date.year(),
+            DialogViewType::Years => era.year(&self.date()) == era.year(&date.date()),
+            DialogViewType::HalfYears => {
+                self.year() == date.year() && self.half_year() == date.half_year()
+            }
+            DialogViewType::Quarters => {
+                self.year() == date.year() && self.quarter() == date.quarter()
+            }
             DialogViewType::Months => self.year() == date.year() && self.month() == date.month(),
+            DialogViewType::Weeks => {
+                self.week_start(first_weekday).date() == date.week_start(first_weekday).date()
+            }
             DialogViewType::Days => self.date() == date.date(),
+            DialogViewType::Hours => self.date() == date.date() && self.hour() == date.hour(),
+            DialogViewType::Minutes => {
+                self.date() == date.date()
+                    && self.hour() == date.hour()
+                    && self.minute() == date.minute()
+            }
         }
     }
 }
 
+/// Returns the number of days from the last `since` weekday to the given weekday
+pub fn days_since(weekday: Weekday, since: Weekday) -> u8 {
+    (weekday.number_days_from_sunday() + 7 - since.number_days_from_sunday()) % 7
+}
+
+/// Returns the first day of the given month with the time of the given datetime,
+/// the given datetime if the month is out of the range of `Date`
+fn first_day_or(
+    datetime: &PrimitiveDateTime,
+    year: YearNumber,
+    month: MonthNumber,
+) -> PrimitiveDateTime {
+    try_from_ymd(year, month, 1)
+        .map(|date| PrimitiveDateTime::new(date, datetime.time()))
+        .unwrap_or(*datetime)
+}
+
+/// Returns the first day of the given year of the given era with the time of the given datetime,
+/// the given datetime if the year is out of the range of `Date`
+fn first_day_of_era_year_or(
+    datetime: &PrimitiveDateTime,
+    era: &CalendarEra,
+    year: YearNumber,
+) -> PrimitiveDateTime {
+    era.first_day_of_year(year)
+        .map(|date| PrimitiveDateTime::new(date, datetime.time()))
+        .unwrap_or(*datetime)
+}
+
+/// Returns the first year of the group of years including the given year,
+/// a multiple of `YEARS_IN_YEAR_SELECTION`, also for negative years
 pub fn year_group_start(year: YearNumber) -> YearNumber {
-    year - (year % YEARS_IN_YEAR_SELECTION)
+    year - year.rem_euclid(YEARS_IN_YEAR_SELECTION)
 }
 
 pub fn year_group_end(year: YearNumber) -> YearNumber {
@@ -155,6 +423,7 @@ mod tests {
         expected, given, //
         case::from_january(create_datetime(1989, 12, 1, 0, 0), create_datetime(1990, 1, 15, 0, 0)),
         case::not_from_january(create_datetime(1990, 2, 1, 0, 0), create_datetime(1990, 3, 22, 0, 0)),
+        case::at_min(create_datetime(-9999, 1, 15, 0, 0), create_datetime(-9999, 1, 15, 0, 0)),
     )]
     fn previous_month(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.previous_month());
@@ -164,15 +433,68 @@ mod tests {
         expected, given, //
         case::from_december(create_datetime(1991, 1, 1, 0, 0), create_datetime(1990, 12, 22, 0, 0)),
         case::not_from_december(create_datetime(1990, 4, 1, 0, 0), create_datetime(1990, 3, 15, 0, 0)),
+        case::at_max(create_datetime(9999, 12, 22, 0, 0), create_datetime(9999, 12, 22, 0, 0)),
     )]
     fn next_month(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.next_month());
     }
 
+    #[rstest(
+        expected_previous, expected_next, given, //
+        case::middle_of_hour(create_datetime(1990, 3, 1, 9, 0), create_datetime(1990, 3, 1, 11, 0), create_datetime(1990, 3, 1, 10, 30)),
+        case::midnight(create_datetime(1990, 2, 28, 23, 0), create_datetime(1990, 3, 1, 1, 0), create_datetime(1990, 3, 1, 0, 0)),
+        case::last_hour(create_datetime(1990, 12, 31, 22, 0), create_datetime(1991, 1, 1, 0, 0), create_datetime(1990, 12, 31, 23, 59)),
+    )]
+    fn previous_and_next_hour(
+        expected_previous: PrimitiveDateTime,
+        expected_next: PrimitiveDateTime,
+        given: PrimitiveDateTime,
+    ) {
+        assert_eq!(expected_previous, given.previous_hour());
+        assert_eq!(expected_next, given.next_hour());
+    }
+
+    #[rstest(
+        expected, given, //
+        case::first_quarter(create_datetime(1989, 10, 1, 0, 0), create_datetime(1990, 2, 15, 0, 0)),
+        case::last_quarter(create_datetime(1990, 7, 1, 0, 0), create_datetime(1990, 12, 31, 0, 0)),
+    )]
+    fn previous_quarter(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
+        assert_eq!(expected, given.previous_quarter());
+    }
+
+    #[rstest(
+        expected, given, //
+        case::first_quarter(create_datetime(1990, 4, 1, 0, 0), create_datetime(1990, 3, 31, 0, 0)),
+        case::last_quarter(create_datetime(1991, 1, 1, 0, 0), create_datetime(1990, 10, 1, 0, 0)),
+    )]
+    fn next_quarter(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
+        assert_eq!(expected, given.next_quarter());
+    }
+
+    #[rstest(
+        expected, given, //
+        case::first_half(create_datetime(1989, 7, 1, 0, 0), create_datetime(1990, 6, 30, 0, 0)),
+        case::second_half(create_datetime(1990, 1, 1, 0, 0), create_datetime(1990, 7, 1, 0, 0)),
+    )]
+    fn previous_half_year(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
+        assert_eq!(expected, given.previous_half_year());
+    }
+
+    #[rstest(
+        expected, given, //
+        case::first_half(create_datetime(1990, 7, 1, 0, 0), create_datetime(1990, 1, 1, 0, 0)),
+        case::second_half(create_datetime(1991, 1, 1, 0, 0), create_datetime(1990, 12, 31, 0, 0)),
+    )]
+    fn next_half_year(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
+        assert_eq!(expected, given.next_half_year());
+    }
+
     #[rstest(
         expected, given, //
         case(create_datetime(1989, 1, 1, 0, 0), create_datetime(1990, 12, 25, 0, 0)),
         case(create_datetime(1990, 1, 1, 0, 0), create_datetime(1991, 3, 22, 0, 0)),
+        case::at_min(create_datetime(-9999, 3, 22, 0, 0), create_datetime(-9999, 3, 22, 0, 0)),
     )]
     fn previous_year(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.previous_year());
@@ -182,6 +504,7 @@ mod tests {
         expected, given, //
         case(create_datetime(1991, 1, 1, 0, 0), create_datetime(1990, 12, 25, 0, 0)),
         case(create_datetime(1992, 1, 1, 0, 0), create_datetime(1991, 3, 22, 0, 0)),
+        case::at_max(create_datetime(9999, 3, 22, 0, 0), create_datetime(9999, 3, 22, 0, 0)),
     )]
     fn next_year(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.next_year());
@@ -193,6 +516,8 @@ mod tests {
         case::at_start(create_datetime(1979, 1, 1, 0, 0), create_datetime(1980, 3, 20, 0, 0)),
         case::at_end(create_datetime(1979, 1, 1, 0, 0), create_datetime(1999, 7, 24, 0, 0)),
         case::next_group(create_datetime(1999, 1, 1, 0, 0), create_datetime(2000, 8, 22, 0, 0)),
+        case::negative(create_datetime(-21, 1, 1, 0, 0), create_datetime(-5, 8, 22, 0, 0)),
+        case::at_min(create_datetime(-9990, 8, 22, 0, 0), create_datetime(-9990, 8, 22, 0, 0)),
     )]
     fn previous_year_group(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.previous_year_group());
@@ -204,11 +529,52 @@ mod tests {
         case::at_start(create_datetime(2000, 1, 1, 0, 0), create_datetime(1980, 3, 20, 0, 0)),
         case::at_end(create_datetime(2000, 1, 1, 0, 0), create_datetime(1999, 7, 24, 0, 0)),
         case::next_group(create_datetime(2020, 1, 1, 0, 0), create_datetime(2000, 8, 22, 0, 0)),
+        case::negative(create_datetime(0, 1, 1, 0, 0), create_datetime(-5, 8, 22, 0, 0)),
+        case::at_max(create_datetime(9990, 8, 22, 0, 0), create_datetime(9990, 8, 22, 0, 0)),
     )]
     fn next_year_group(expected: PrimitiveDateTime, given: PrimitiveDateTime) {
         assert_eq!(expected, given.next_year_group());
     }
 
+    const BUDDHIST: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: false,
+    };
+    const BUDDHIST_APRIL: CalendarEra = CalendarEra::Buddhist {
+        april_new_year: true,
+    };
+
+    #[rstest(
+        expected, era, given, //
+        case::christian(create_datetime(1979, 1, 1, 0, 0), CalendarEra::Christian, create_datetime(1990, 1, 1, 0, 0)),
+        case::buddhist_in_middle(create_datetime(2016, 1, 1, 0, 0), BUDDHIST, create_datetime(2024, 5, 1, 0, 0)),
+        case::buddhist_at_start(create_datetime(2016, 1, 1, 0, 0), BUDDHIST, create_datetime(2017, 1, 1, 0, 0)),
+        case::buddhist_at_end(create_datetime(2016, 1, 1, 0, 0), BUDDHIST, create_datetime(2036, 12, 31, 0, 0)),
+        case::buddhist_previous_group(create_datetime(1996, 1, 1, 0, 0), BUDDHIST, create_datetime(2016, 3, 20, 0, 0)),
+        case::april_new_year_before_april(create_datetime(1916, 4, 1, 0, 0), BUDDHIST_APRIL, create_datetime(1937, 3, 15, 0, 0)),
+        case::april_new_year_from_april(create_datetime(1936, 4, 1, 0, 0), BUDDHIST_APRIL, create_datetime(1937, 4, 1, 0, 0)),
+    )]
+    fn previous_year_group_in(
+        expected: PrimitiveDateTime,
+        era: CalendarEra,
+        given: PrimitiveDateTime,
+    ) {
+        assert_eq!(expected, given.previous_year_group_in(&era));
+    }
+
+    #[rstest(
+        expected, era, given, //
+        case::christian(create_datetime(2000, 1, 1, 0, 0), CalendarEra::Christian, create_datetime(1990, 1, 1, 0, 0)),
+        case::buddhist_in_middle(create_datetime(2037, 1, 1, 0, 0), BUDDHIST, create_datetime(2024, 5, 1, 0, 0)),
+        case::buddhist_at_start(create_datetime(2037, 1, 1, 0, 0), BUDDHIST, create_datetime(2017, 1, 1, 0, 0)),
+        case::buddhist_at_end(create_datetime(2037, 1, 1, 0, 0), BUDDHIST, create_datetime(2036, 12, 31, 0, 0)),
+        case::buddhist_previous_group(create_datetime(2017, 1, 1, 0, 0), BUDDHIST, create_datetime(2016, 3, 20, 0, 0)),
+        case::april_new_year_before_april(create_datetime(1937, 4, 1, 0, 0), BUDDHIST_APRIL, create_datetime(1937, 3, 15, 0, 0)),
+        case::april_new_year_from_april(create_datetime(1957, 1, 1, 0, 0), BUDDHIST_APRIL, create_datetime(1937, 4, 1, 0, 0)),
+    )]
+    fn next_year_group_in(expected: PrimitiveDateTime, era: CalendarEra, given: PrimitiveDateTime) {
+        assert_eq!(expected, given.next_year_group_in(&era));
+    }
+
     #[rstest(
         expected, given, //
         case(create_datetime(1990, 12, 1, 0, 0), create_datetime(1990, 12, 15, 0, 0)),
@@ -223,10 +589,25 @@ mod tests {
         case::years_different(false, create_datetime(1990, 1, 1, 0, 0), DialogViewType::Years, create_datetime(1989, 1, 1, 0, 0)),
         case::years_equal(true, create_datetime(1990, 1, 1, 0, 0), DialogViewType::Years, create_datetime(1990, 5, 15, 0, 0)),
 
+        case::half_years_equal(true, create_datetime(1990, 3, 1, 0, 0), DialogViewType::HalfYears, create_datetime(1990, 6, 30, 0, 0)),
+        case::half_years_different(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::HalfYears, create_datetime(1990, 7, 1, 0, 0)),
+        case::quarters_equal(true, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Quarters, create_datetime(1990, 1, 1, 0, 0)),
+        case::quarters_different(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Quarters, create_datetime(1990, 4, 1, 0, 0)),
+        case::quarters_different_year(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Quarters, create_datetime(1991, 3, 1, 0, 0)),
+
         case::months_different_year(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Months, create_datetime(1989, 3, 1, 0, 0)),
         case::months_different_month(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Months, create_datetime(1990, 4, 1, 0, 0)),
         case::months_equal(true, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Months, create_datetime(1990, 3, 15, 0, 0)),
 
+        case::weeks_previous_month(true, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Weeks, create_datetime(1990, 2, 26, 0, 0)),
+        case::weeks_sunday(true, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Weeks, create_datetime(1990, 3, 4, 0, 0)),
+        case::weeks_next_monday(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Weeks, create_datetime(1990, 3, 5, 0, 0)),
+
+        case::hours_equal(true, create_datetime(1990, 3, 1, 10, 0), DialogViewType::Hours, create_datetime(1990, 3, 1, 10, 59)),
+        case::hours_different(false, create_datetime(1990, 3, 1, 10, 0), DialogViewType::Hours, create_datetime(1990, 3, 1, 11, 0)),
+        case::minutes_equal(true, create_datetime(1990, 3, 1, 10, 5), DialogViewType::Minutes, create_datetime(1990, 3, 1, 10, 5)),
+        case::minutes_different_day(false, create_datetime(1990, 3, 1, 10, 5), DialogViewType::Minutes, create_datetime(1990, 3, 2, 10, 5)),
+
         case::days_different_year(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Days, create_datetime(1989, 3, 1, 0, 0)),
         case::days_different_month(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Days, create_datetime(1990, 4, 1, 0, 0)),
         case::days_different_day(false, create_datetime(1990, 3, 1, 0, 0), DialogViewType::Days, create_datetime(1990, 3, 15, 0, 0)),
@@ -279,4 +660,189 @@ mod tests {
     fn test_year_group_range(expected: RangeInclusive<YearNumber>, input: YearNumber) {
         assert_eq!(expected, year_group_range(input));
     }
+
+    #[rstest(
+        expected, era, viewed_date, tested_date, //
+        case::buddhist_same_year(true, BUDDHIST, create_datetime(1930, 1, 1, 0, 0), create_datetime(1930, 5, 1, 0, 0)),
+        case::april_new_year_same_year(true, CalendarEra::Buddhist { april_new_year: true }, create_datetime(1930, 4, 1, 0, 0), create_datetime(1931, 3, 31, 0, 0)),
+        case::april_new_year_different_year(false, CalendarEra::Buddhist { april_new_year: true }, create_datetime(1930, 3, 1, 0, 0), create_datetime(1930, 5, 1, 0, 0)),
+        case::april_new_year_from_1941(false, CalendarEra::Buddhist { april_new_year: true }, create_datetime(1941, 1, 1, 0, 0), create_datetime(1940, 12, 31, 0, 0)),
+    )]
+    fn contains_in_years(
+        expected: bool,
+        era: CalendarEra,
+        viewed_date: PrimitiveDateTime,
+        tested_date: PrimitiveDateTime,
+    ) {
+        assert_eq!(
+            expected,
+            viewed_date.contains_in(&era, Weekday::Monday, &DialogViewType::Years, &tested_date)
+        );
+    }
+
+    #[rstest(
+        expected, first_weekday, tested_date, //
+        case::monday_same_week(true, Weekday::Monday, create_datetime(1990, 3, 4, 0, 0)),
+        case::monday_next_week(false, Weekday::Monday, create_datetime(1990, 3, 5, 0, 0)),
+        case::sunday_next_week(false, Weekday::Sunday, create_datetime(1990, 3, 4, 0, 0)),
+        case::sunday_same_week(true, Weekday::Sunday, create_datetime(1990, 2, 25, 0, 0)),
+    )]
+    fn contains_in_weeks(expected: bool, first_weekday: Weekday, tested_date: PrimitiveDateTime) {
+        assert_eq!(
+            expected,
+            create_datetime(1990, 3, 1, 0, 0).contains_in(
+                &CalendarEra::Christian,
+                first_weekday,
+                &DialogViewType::Weeks,
+                &tested_date
+            )
+        );
+    }
+
+    #[rstest(
+        year => [2023, 2024],
+        month => [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+        first_weekday => [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ],
+        six_weeks => [false, true],
+    )]
+    fn calendar_dates(
+        year: YearNumber,
+        month: MonthNumber,
+        first_weekday: Weekday,
+        six_weeks: bool,
+    ) {
+        let viewed_date = create_datetime(year, month, 15, 0, 0);
+        let dates = viewed_date.calendar_dates(first_weekday, six_weeks);
+        let month_dates = viewed_date.dates_in_month();
+
+        assert_eq!(0, dates.len() % 7);
+        if six_weeks {
+            assert_eq!(42, dates.len());
+        } else {
+            // the first and the last rows include a day of the month
+            assert!(dates[..7].contains(&month_dates[0]));
+            assert!(dates[dates.len() - 7..].contains(month_dates.last().unwrap()));
+        }
+        assert_eq!(first_weekday, dates[0].weekday());
+        assert!(dates.windows(2).all(|w| w[1] - w[0] == Duration::days(1)));
+        assert!(month_dates.iter().all(|date| dates.contains(date)));
+    }
+
+    #[rstest(
+        expected, given, first_weekday, //
+        case::monday(create_datetime(1990, 2, 26, 10, 0), create_datetime(1990, 3, 1, 10, 0), Weekday::Monday),
+        case::sunday(create_datetime(1990, 2, 25, 10, 0), create_datetime(1990, 3, 1, 10, 0), Weekday::Sunday),
+        case::same_day(create_datetime(1990, 3, 1, 10, 0), create_datetime(1990, 3, 1, 10, 0), Weekday::Thursday),
+    )]
+    fn week_start(expected: PrimitiveDateTime, given: PrimitiveDateTime, first_weekday: Weekday) {
+        assert_eq!(expected, given.week_start(first_weekday));
+    }
+
+    #[rstest(
+        expected, weekday, since, //
+        case::same(0, Weekday::Monday, Weekday::Monday),
+        case::next(1, Weekday::Tuesday, Weekday::Monday),
+        case::before(6, Weekday::Sunday, Weekday::Monday),
+        case::sunday_start(6, Weekday::Saturday, Weekday::Sunday),
+    )]
+    fn test_days_since(expected: u8, weekday: Weekday, since: Weekday) {
+        assert_eq!(expected, days_since(weekday, since));
+    }
+
+    #[rstest(
+        expected, year, //
+        case::positive(1980, 1999),
+        case::zero(0, 0),
+        case::negative(-20, -1),
+        case::negative_start(-20, -20),
+        case::negative_previous_group(-40, -21),
+    )]
+    fn year_group_start_of(expected: YearNumber, year: YearNumber) {
+        assert_eq!(expected, year_group_start(year));
+    }
+
+    #[test]
+    fn navigation_saturates_at_min() {
+        let min = create_datetime(-9999, 1, 1, 0, 30);
+        assert_eq!(min, min.previous_hour());
+        assert_eq!(min, min.previous_day());
+        assert_eq!(min, min.previous_quarter());
+        assert_eq!(min, min.previous_half_year());
+        assert_eq!(create_datetime(-9999, 1, 1, 1, 0), min.next_hour());
+        assert_eq!(create_datetime(-9999, 4, 1, 0, 30), min.next_quarter());
+    }
+
+    #[test]
+    fn navigation_saturates_at_max() {
+        let max = create_datetime(9999, 12, 31, 23, 30);
+        assert_eq!(max, max.next_hour());
+        assert_eq!(max, max.next_day());
+        assert_eq!(max, max.next_quarter());
+        assert_eq!(max, max.next_half_year());
+        assert_eq!(create_datetime(9999, 12, 31, 22, 0), max.previous_hour());
+        assert_eq!(create_datetime(9999, 7, 1, 23, 30), max.previous_quarter());
+    }
+
+    #[test]
+    fn dates_at_edges() {
+        let max = create_datetime(9999, 12, 31, 0, 0);
+        assert_eq!(31, max.dates_in_month().len());
+        assert_eq!(Some(&max), max.calendar_dates(Weekday::Sunday, true).last());
+        let min = create_datetime(-9999, 1, 1, 0, 0);
+        assert_eq!(
+            Some(&min),
+            min.calendar_dates(Weekday::Sunday, true).first()
+        );
+    }
+
+    #[rstest(
+        expected, given, months, //
+        case::same_day(create_datetime(2024, 3, 15, 10, 30), create_datetime(2024, 2, 15, 10, 30), 1),
+        case::leap_year_month_end(create_datetime(2024, 2, 29, 0, 0), create_datetime(2024, 1, 31, 0, 0), 1),
+        case::common_year_month_end(create_datetime(2023, 2, 28, 0, 0), create_datetime(2023, 1, 31, 0, 0), 1),
+        case::shorter_previous_month(create_datetime(2024, 4, 30, 0, 0), create_datetime(2024, 5, 31, 0, 0), -1),
+        case::over_year(create_datetime(2025, 2, 28, 0, 0), create_datetime(2024, 11, 30, 0, 0), 3),
+        case::back_over_year(create_datetime(2023, 11, 30, 0, 0), create_datetime(2024, 1, 31, 0, 0), -2),
+        case::many_years(create_datetime(1990, 1, 15, 0, 0), create_datetime(2000, 1, 15, 0, 0), -120),
+        case::zero(create_datetime(2024, 1, 31, 0, 0), create_datetime(2024, 1, 31, 0, 0), 0),
+        case::at_max(create_datetime(9999, 12, 15, 0, 0), create_datetime(9999, 12, 15, 0, 0), 1),
+        case::at_min(create_datetime(-9999, 1, 15, 0, 0), create_datetime(-9999, 1, 15, 0, 0), -1),
+        case::overflow(create_datetime(2024, 1, 15, 0, 0), create_datetime(2024, 1, 15, 0, 0), i32::MAX),
+    )]
+    fn add_months(expected: PrimitiveDateTime, given: PrimitiveDateTime, months: i32) {
+        assert_eq!(expected, given.add_months(months));
+    }
+
+    #[rstest(
+        expected, given, years, //
+        case::same_day(create_datetime(2025, 5, 15, 10, 30), create_datetime(2024, 5, 15, 10, 30), 1),
+        case::leap_day_to_common_year(create_datetime(2025, 2, 28, 0, 0), create_datetime(2024, 2, 29, 0, 0), 1),
+        case::leap_day_to_leap_year(create_datetime(2020, 2, 29, 0, 0), create_datetime(2024, 2, 29, 0, 0), -4),
+        case::at_max(create_datetime(9999, 6, 15, 0, 0), create_datetime(9999, 6, 15, 0, 0), 1),
+    )]
+    fn add_years(expected: PrimitiveDateTime, given: PrimitiveDateTime, years: i32) {
+        assert_eq!(expected, given.add_years(years));
+    }
+
+    #[test]
+    fn previous_and_next_month_same_day() {
+        let given = create_datetime(2024, 3, 31, 8, 0);
+        assert_eq!(
+            create_datetime(2024, 2, 29, 8, 0),
+            given.previous_month_same_day()
+        );
+        assert_eq!(
+            create_datetime(2024, 4, 30, 8, 0),
+            given.next_month_same_day()
+        );
+        assert_eq!(create_datetime(2024, 4, 1, 8, 0), given.next_month());
+    }
 }
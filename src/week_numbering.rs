@@ -0,0 +1,61 @@
+use time::{Date, Weekday};
+
+use crate::viewed_date::days_since;
+
+/// Numbering of the weeks of a year, displayed in front of the rows of the days view.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeekNumbering {
+    /// ISO-8601 weeks from Monday to Sunday, the week 1 includes the first Thursday of the year
+    #[default]
+    Iso,
+    /// US weeks from Sunday to Saturday, the week 1 includes 1 January
+    Us,
+}
+
+impl WeekNumbering {
+    /// Returns the first day of the weeks of this numbering
+    pub const fn first_weekday(&self) -> Weekday {
+        match self {
+            WeekNumbering::Iso => Weekday::Monday,
+            WeekNumbering::Us => Weekday::Sunday,
+        }
+    }
+
+    /// Returns the number of the week including the given date, from 1 to 53 for ISO weeks
+    /// and from 1 to 54 for US weeks
+    pub fn week_number(&self, date: &Date) -> u8 {
+        match self {
+            WeekNumbering::Iso => date.iso_week(),
+            WeekNumbering::Us => {
+                let first_day = Date::from_ordinal_date(date.year(), 1)
+                    .expect("the first day of a year is valid");
+                let offset = days_since(first_day.weekday(), Weekday::Sunday);
+                ((date.ordinal() + u16::from(offset) - 1) / 7 + 1) as u8
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+    use time::macros::date;
+
+    #[rstest(
+        expected, numbering, date, //
+        case::iso_first_week(1, WeekNumbering::Iso, date!(2024 - 01 - 01)),
+        case::iso_previous_year(53, WeekNumbering::Iso, date!(2021 - 01 - 03)),
+        case::iso_next_year(1, WeekNumbering::Iso, date!(2024 - 12 - 30)),
+        case::iso_middle(20, WeekNumbering::Iso, date!(2024 - 05 - 15)),
+        case::us_first_day(1, WeekNumbering::Us, date!(2021 - 01 - 01)),
+        case::us_first_sunday(2, WeekNumbering::Us, date!(2021 - 01 - 03)),
+        case::us_middle(20, WeekNumbering::Us, date!(2024 - 05 - 15)),
+        case::us_last_week(54, WeekNumbering::Us, date!(2000 - 12 - 31)),
+    )]
+    fn week_number(expected: u8, numbering: WeekNumbering, date: Date) {
+        assert_eq!(expected, numbering.week_number(&date));
+    }
+}